@@ -0,0 +1,140 @@
+use std::time::{Duration, Instant};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use rand::Rng;
+use pprof::criterion::{Output, PProfProfiler};
+
+use genetic_algorithms::traits::{GeneT, GenotypeT};
+use genetic_algorithms::parallel::WorkerPool;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Gene {
+    pub id: i32,
+}
+impl GeneT for Gene {
+    fn get_id(&self) -> i32 {
+        self.id
+    }
+    fn set_id(&mut self, id: i32) -> &mut Self {
+        self.id = id;
+        self
+    }
+}
+
+// Genotype with an expensive fitness function, repeated over the dna
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ExpensiveGenotype {
+    dna: Vec<Gene>,
+    pub fitness: f64,
+    pub age: i32,
+    pub rounds: usize,
+}
+impl GenotypeT for ExpensiveGenotype {
+    type Gene = Gene;
+
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        let mut fitness = 0.0;
+        for round in 0..self.rounds {
+            for gene in &self.dna {
+                fitness += (gene.id as f64 + round as f64).sin().abs().sqrt();
+            }
+        }
+        self.fitness = black_box(fitness);
+    }
+}
+
+// Setup function to create a population whose evaluation costs the given number of rounds per individual
+fn setup_population(population_size: usize, rounds: usize) -> Vec<ExpensiveGenotype> {
+    let mut rng = rand::thread_rng();
+    (0..population_size)
+        .map(|_| ExpensiveGenotype {
+            fitness: 0.0,
+            dna: (0..100).map(|_| Gene { id: rng.gen_range(0..255) }).collect(),
+            age: 0,
+            rounds,
+        })
+        .collect()
+}
+
+// Mean time of the evaluation of the population with the given pool
+fn evaluation_time(pool: &WorkerPool, individuals: &mut [ExpensiveGenotype], iterations: u32) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        pool.evaluate(individuals);
+    }
+    start.elapsed() / iterations
+}
+
+// Prints the speedup of the evaluation with each number of threads against a single thread
+fn report_speedup(individuals: &[ExpensiveGenotype], threads: &[usize], rounds: usize) {
+    let mut individuals = individuals.to_vec();
+    let single_thread = evaluation_time(&WorkerPool::new(1), &mut individuals, 5);
+    for &threads in threads {
+        let time = evaluation_time(&WorkerPool::new(threads), &mut individuals, 5);
+        println!("fitness_evaluation rounds_{}: {} threads {:?}, speedup against 1 thread {:.2}x",
+                 rounds, threads, time, single_thread.as_secs_f64() / time.as_secs_f64());
+    }
+}
+
+// Benchmark of the fitness evaluation with different threads. The fitness function costs milliseconds per individual
+// with the most rounds, so the time must decrease almost linearly with the threads, up to the number of cores
+fn benchmark_fitness_evaluation(c: &mut Criterion) {
+    let population_size = 256;
+    let rounds = vec![100, 1000];
+    let threads = vec![1, 2, 4, 8];
+
+    let mut group = c.benchmark_group("fitness_evaluation");
+    group.throughput(Throughput::Elements(population_size as u64));
+    group.sample_size(10);
+
+    for &rounds in &rounds {
+        let individuals = setup_population(population_size, rounds);
+
+        for &threads in &threads {
+            let pool = WorkerPool::new(threads);
+            group.bench_with_input(
+                BenchmarkId::new(format!("evaluation {} threads", threads), format!("population_{}_rounds_{}", population_size, rounds)),
+                &individuals,
+                |b, individuals| {
+                    let mut individuals = individuals.clone();
+                    b.iter(|| {
+                        pool.evaluate(&mut individuals);
+                    });
+                },
+            );
+        }
+        report_speedup(&individuals, &threads, rounds);
+    }
+    group.finish();
+}
+
+// Create the benchmark group with profiling
+criterion_group! {
+    name = fitness_benchmarks;
+    config = Criterion::default()
+        .with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
+    targets = benchmark_fitness_evaluation
+}
+
+criterion_main!(fitness_benchmarks);
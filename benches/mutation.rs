@@ -82,7 +82,7 @@ fn benchmark_mutation_methods(c: &mut Criterion) {
             &individual,
             |b, individual| {
                 b.iter(|| {
-                    let _ = swap(&mut individual.clone());
+                    swap(&mut individual.clone());
                 });
             },
         );
@@ -93,7 +93,7 @@ fn benchmark_mutation_methods(c: &mut Criterion) {
             &individual,
             |b, individual| {
                 b.iter(|| {
-                    let _ = inversion(&mut individual.clone());
+                    inversion(&mut individual.clone());
                 });
             },
         );
@@ -104,7 +104,7 @@ fn benchmark_mutation_methods(c: &mut Criterion) {
             &individual,
             |b, individual| {
                 b.iter(|| {
-                    let _ = scramble(&mut individual.clone());
+                    scramble(&mut individual.clone());
                 });
             },
         );
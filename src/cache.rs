@@ -0,0 +1,153 @@
+use std::{collections::{BTreeMap, HashMap}, hash::Hash, sync::Mutex};
+use log::trace;
+use crate::traits::{GeneT, GenotypeT};
+
+/**
+ * Statistics of the use of the fitness cache in a run
+ */
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheStatistics {
+    //Fitness values found in the cache
+    pub hits: usize,
+    //Fitness values calculated because they were not in the cache
+    pub misses: usize,
+    //Proportion of the lookups found in the cache
+    pub hit_rate: f64,
+}
+
+/**
+ * Key of a dna in the fitness cache: the id and the bits of the value of each gene
+ */
+pub type DnaKey = Vec<(i32, u64)>;
+
+/**
+ * Cache of the fitness values, keyed by the ids and the values of the genes of the dna. The whole key is compared on each lookup,
+ * so different dnas never share their fitness. When it's full, the least recently used value is discarded.
+ */
+#[derive(Clone, Debug)]
+pub struct FitnessCache<K = DnaKey> {
+    capacity: usize,
+    //Fitness and last use of each key
+    entries: HashMap<K, (f64, u64)>,
+    //Keys by their last use, from the least recently used
+    uses: BTreeMap<u64, K>,
+    clock: u64,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Hash + Eq + Clone> FitnessCache<K> {
+    /**
+     * Creates an empty cache that keeps up to the given number of fitness values
+     */
+    pub fn new(capacity: usize) -> Self {
+        FitnessCache { capacity: capacity.max(1), entries: HashMap::new(), uses: BTreeMap::new(), clock: 0, hits: 0, misses: 0 }
+    }
+
+    /**
+     * Gets the fitness of the key, if it's in the cache, counting the hit or the miss
+     */
+    pub fn get(&mut self, key: &K) -> Option<f64> {
+        self.clock += 1;
+        match self.entries.get_mut(key) {
+            Some((fitness, last_use)) => {
+                self.uses.remove(last_use);
+                self.uses.insert(self.clock, key.clone());
+                *last_use = self.clock;
+                self.hits += 1;
+                Some(*fitness)
+            },
+            None => {
+                self.misses += 1;
+                None
+            },
+        }
+    }
+
+    /**
+     * Sets the fitness of the key, discarding the least recently used value if the cache is full
+     */
+    pub fn insert(&mut self, key: K, fitness: f64) {
+        self.clock += 1;
+        if let Some((_, last_use)) = self.entries.insert(key.clone(), (fitness, self.clock)) {
+            self.uses.remove(&last_use);
+        } else if self.entries.len() > self.capacity {
+            if let Some((last_use, oldest)) = self.uses.pop_first() {
+                trace!(target="cache_events", method="insert"; "Discarding the key used at {}", last_use);
+                self.entries.remove(&oldest);
+            }
+        }
+        self.uses.insert(self.clock, key);
+    }
+
+    /**
+     * Number of fitness values in the cache
+     */
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /**
+     * Hits, misses and hit rate of the lookups since the cache was created
+     */
+    pub fn statistics(&self) -> CacheStatistics {
+        let lookups = self.hits + self.misses;
+        let hit_rate = if lookups == 0 {0.0} else {self.hits as f64 / lookups as f64};
+        CacheStatistics { hits: self.hits, misses: self.misses, hit_rate }
+    }
+}
+
+/**
+ * Gets the key of a dna in the fitness cache, with the ids and the values of its genes, so the genes with real values are told apart
+ */
+pub fn dna_key<U: GenotypeT>(dna: &[U::Gene]) -> DnaKey {
+    dna.iter().map(|gene| (gene.get_id(), gene.get_value().to_bits())).collect()
+}
+
+/**
+ * Sets the fitness of an individual already evaluated in the cache, without counting a lookup
+ */
+pub fn store_fitness<U: GenotypeT>(individual: &U, cache: Option<&Mutex<FitnessCache>>) {
+    if let Some(cache) = cache {
+        cache.lock().unwrap().insert(dna_key::<U>(individual.get_dna()), individual.get_fitness());
+    }
+}
+
+/**
+ * Calculates the fitness of the individual, taking it from the cache if it's there. The cache is only locked for the lookup and the insertion,
+ * so the evaluations of different threads run concurrently. Returns whether the fitness function was called.
+ */
+pub fn calculate_fitness<U: GenotypeT>(individual: &mut U, cache: Option<&Mutex<FitnessCache>>) -> bool {
+    let evaluated = match cache {
+        None => {
+            individual.calculate_fitness();
+            true
+        },
+        Some(cache) => {
+            let key = dna_key::<U>(individual.get_dna());
+            let cached = cache.lock().unwrap().get(&key);
+            match cached {
+                Some(fitness) => {
+                    individual.set_fitness(fitness);
+                    false
+                },
+                None => {
+                    individual.calculate_fitness();
+                    cache.lock().unwrap().insert(key, individual.get_fitness());
+                    true
+                },
+            }
+        },
+    };
+    individual.set_fitness_valid(true);
+    evaluated
+}
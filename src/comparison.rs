@@ -0,0 +1,345 @@
+use std::fmt;
+use crate::{configuration::{LimitConfiguration, ProblemSolving}, experiment::ConfigurationResult, population::Population, statistics::Summary, traits::GenotypeT};
+
+//Significance level of the tests of the report
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+//Critical values of the Nemenyi test for a significance level of 0.05, from 2 to 10 configurations
+const NEMENYI_CRITICAL_VALUES: [f64; 9] = [1.960, 2.343, 2.569, 2.728, 2.850, 2.949, 3.031, 3.102, 3.164];
+
+/**
+ * Final fitness of the runs of a configuration
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+impl Sample {
+    /**
+     * Creates a sample from the final fitness of each run
+     */
+    pub fn new(name: &str, values: Vec<f64>) -> Self {
+        Sample { name: name.to_string(), values }
+    }
+
+    /**
+     * Creates a sample from the populations returned by several runs of the `Ga`, with the best fitness of each run
+     */
+    pub fn from_populations<U: GenotypeT>(name: &str, populations: &[Population<U>]) -> Self {
+        let values = populations.iter().map(|population| match population.statistics.last() {
+            Some(generation) => generation.best_so_far,
+            None => population.individuals.first().map_or(f64::NAN, |individual| individual.get_fitness()),
+        }).collect();
+        Sample::new(name, values)
+    }
+}
+
+impl From<&ConfigurationResult> for Sample {
+    fn from(result: &ConfigurationResult) -> Self {
+        Sample::new(&result.name, result.runs.iter().map(|run| run.best_fitness).collect())
+    }
+}
+
+/**
+ * Result of the Wilcoxon rank-sum / Mann-Whitney U test
+ */
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MannWhitney {
+    //U statistic of the first sample
+    pub u: f64,
+    //Standardized statistic, with the continuity and ties corrections
+    pub z: f64,
+    //Two-sided p-value of the normal approximation
+    pub p_value: f64,
+}
+
+/**
+ * Result of the Friedman test
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub struct Friedman {
+    //Chi-squared statistic, with the ties correction
+    pub statistic: f64,
+    pub p_value: f64,
+    //Mean rank of each configuration over the runs, where 1 is the best
+    pub mean_ranks: Vec<f64>,
+}
+
+/**
+ * Comparison of two configurations
+ */
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PairwiseComparison {
+    pub first: usize,
+    pub second: usize,
+    pub mann_whitney: MannWhitney,
+    //Probability that a run of the first configuration is better than a run of the second one
+    pub a12: f64,
+}
+
+/**
+ * Statistical comparison of the final fitness of several configurations
+ */
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub samples: Vec<Sample>,
+    pub summaries: Vec<Summary>,
+    pub pairwise: Vec<PairwiseComparison>,
+    //Friedman test, only if the configurations have the same number of runs, with more than one run
+    pub friedman: Option<Friedman>,
+    //Critical difference of the mean ranks of the Nemenyi test, only with the Friedman test and up to 10 configurations
+    pub nemenyi_critical_difference: Option<f64>,
+}
+
+impl Comparison {
+    /**
+     * Compares the samples, where the better fitness depends on the problem solving
+     */
+    pub fn new(samples: Vec<Sample>, limit_configuration: &LimitConfiguration) -> Self {
+        let scores: Vec<Vec<f64>> = samples.iter().map(|sample| sample.values.iter().map(|value| score(*value, limit_configuration)).collect()).collect();
+        let summaries = samples.iter().map(|sample| Summary::new(&sample.values)).collect();
+
+        //1- Pairwise comparisons
+        let mut pairwise = Vec::new();
+        for first in 0..samples.len() {
+            for second in first + 1..samples.len() {
+                pairwise.push(PairwiseComparison { first, second, mann_whitney: mann_whitney_u(&scores[first], &scores[second]), a12: a12(&scores[first], &scores[second]) });
+            }
+        }
+
+        //2- Comparison of all the configurations, where the runs with the same index are a block
+        let blocks = scores.first().map_or(0, |values| values.len());
+        let friedman = if scores.len() > 1 && blocks > 1 && scores.iter().all(|values| values.len() == blocks) {Some(friedman(&scores))} else {None};
+        let nemenyi_critical_difference = friedman.as_ref().and_then(|_| nemenyi_critical_difference(scores.len(), blocks));
+
+        Comparison { samples, summaries, pairwise, friedman, nemenyi_critical_difference }
+    }
+
+    /**
+     * Writes the comparison as a markdown report
+     */
+    pub fn to_markdown(&self) -> String {
+        let mut report = String::from("## Final fitness\n\n| Configuration | Runs | Mean | Median | Std dev | IQR | Min | Max |\n|---|---|---|---|---|---|---|---|\n");
+        for (sample, summary) in self.samples.iter().zip(&self.summaries) {
+            report.push_str(&format!("| {} | {} | {:.6} | {:.6} | {:.6} | {:.6} | {:.6} | {:.6} |\n", sample.name, sample.values.len(), summary.mean, summary.median,
+                                     summary.std_dev, summary.iqr, summary.min, summary.max));
+        }
+
+        report.push_str(&format!("\n## Pairwise comparisons\n\nMann-Whitney U test (significance level {}) and Vargha-Delaney A12 effect size.\n\n", SIGNIFICANCE_LEVEL));
+        report.push_str("| A | B | U | p-value | A12 | Effect | Result |\n|---|---|---|---|---|---|---|\n");
+        for comparison in &self.pairwise {
+            let (first, second) = (&self.samples[comparison.first].name, &self.samples[comparison.second].name);
+            let result = if comparison.mann_whitney.p_value >= SIGNIFICANCE_LEVEL {String::from("No significant difference")}
+                         else if comparison.a12 > 0.5 {format!("{} is better", first)} else {format!("{} is better", second)};
+            report.push_str(&format!("| {} | {} | {} | {:.4} | {:.3} | {} | {} |\n", first, second, comparison.mann_whitney.u, comparison.mann_whitney.p_value,
+                                     comparison.a12, effect_size(comparison.a12), result));
+        }
+
+        if let Some(friedman) = &self.friedman {
+            report.push_str(&format!("\n## Friedman test\n\nStatistic {:.4}, p-value {:.4}: ", friedman.statistic, friedman.p_value));
+            report.push_str(if friedman.p_value < SIGNIFICANCE_LEVEL {"the configurations are significantly different.\n\n"} else {"no significant difference between the configurations.\n\n"});
+            report.push_str("| Configuration | Mean rank |\n|---|---|\n");
+            for (sample, rank) in self.samples.iter().zip(&friedman.mean_ranks) {
+                report.push_str(&format!("| {} | {:.3} |\n", sample.name, rank));
+            }
+
+            if let Some(critical_difference) = self.nemenyi_critical_difference {
+                report.push_str(&format!("\nNemenyi post-hoc test: critical difference {:.4}.\n\n| A | B | Rank difference | Result |\n|---|---|---|---|\n", critical_difference));
+                for comparison in &self.pairwise {
+                    let difference = (friedman.mean_ranks[comparison.first] - friedman.mean_ranks[comparison.second]).abs();
+                    report.push_str(&format!("| {} | {} | {:.3} | {} |\n", self.samples[comparison.first].name, self.samples[comparison.second].name, difference,
+                                             if difference > critical_difference {"Significant"} else {"Not significant"}));
+                }
+            }
+        }
+        report
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_markdown())
+    }
+}
+
+/**
+ * Wilcoxon rank-sum / Mann-Whitney U test between two samples, with the normal approximation
+ */
+pub fn mann_whitney_u(sample_1: &[f64], sample_2: &[f64]) -> MannWhitney {
+    let (n_1, n_2) = (sample_1.len() as f64, sample_2.len() as f64);
+    if sample_1.is_empty() || sample_2.is_empty() {
+        return MannWhitney { u: 0.0, z: 0.0, p_value: 1.0 };
+    }
+    let values: Vec<f64> = sample_1.iter().chain(sample_2).copied().collect();
+    let (ranks, ties) = ranks(&values);
+    let u = ranks[..sample_1.len()].iter().sum::<f64>() - n_1 * (n_1 + 1.0) / 2.0;
+
+    //Variance of U with the ties correction
+    let n = n_1 + n_2;
+    let variance = n_1 * n_2 / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        return MannWhitney { u, z: 0.0, p_value: 1.0 };
+    }
+    let difference = u - n_1 * n_2 / 2.0;
+    let z = difference.signum() * (difference.abs() - 0.5).max(0.0) / variance.sqrt();
+    MannWhitney { u, z, p_value: (2.0 * normal_upper_tail(z.abs())).min(1.0) }
+}
+
+/**
+ * Vargha-Delaney A12 effect size: probability that a value of the first sample is greater than a value of the second one, counting the ties as half
+ */
+pub fn a12(sample_1: &[f64], sample_2: &[f64]) -> f64 {
+    if sample_1.is_empty() || sample_2.is_empty() {
+        return 0.5;
+    }
+    let wins: f64 = sample_1.iter().map(|value_1| sample_2.iter().map(|value_2| match value_1.total_cmp(value_2) {
+        std::cmp::Ordering::Greater => 1.0,
+        std::cmp::Ordering::Equal => 0.5,
+        std::cmp::Ordering::Less => 0.0,
+    }).sum::<f64>()).sum();
+    wins / (sample_1.len() * sample_2.len()) as f64
+}
+
+/**
+ * Friedman test of several samples, where the values with the same index are a block. The greater values get the best ranks.
+ */
+pub fn friedman(samples: &[Vec<f64>]) -> Friedman {
+    let (k, blocks) = (samples.len() as f64, samples.first().map_or(0, |values| values.len()));
+    let mut rank_sums = vec![0.0; samples.len()];
+    let mut ties = 0.0;
+    for block in 0..blocks {
+        //The values are negated so the greater values get the lower ranks
+        let values: Vec<f64> = samples.iter().map(|values| -values[block]).collect();
+        let (block_ranks, block_ties) = ranks(&values);
+        rank_sums.iter_mut().zip(block_ranks).for_each(|(sum, rank)| *sum += rank);
+        ties += block_ties;
+    }
+    let n = blocks as f64;
+    let mean_ranks: Vec<f64> = rank_sums.iter().map(|sum| sum / n).collect();
+
+    //Chi-squared statistic with the ties correction
+    let correction = 1.0 - ties / (n * k * (k * k - 1.0));
+    let statistic = if correction <= 0.0 {0.0} else {
+        12.0 * n / (k * (k + 1.0)) * mean_ranks.iter().map(|rank| (rank - (k + 1.0) / 2.0).powi(2)).sum::<f64>() / correction
+    };
+    Friedman { statistic, p_value: regularized_gamma_upper((k - 1.0) / 2.0, statistic / 2.0), mean_ranks }
+}
+
+/**
+ * Critical difference of the mean ranks of the Nemenyi test for a significance level of 0.05, for 2 to 10 configurations
+ */
+pub fn nemenyi_critical_difference(configurations: usize, blocks: usize) -> Option<f64> {
+    let critical_value = NEMENYI_CRITICAL_VALUES.get(configurations.checked_sub(2)?)?;
+    let k = configurations as f64;
+    Some(critical_value * (k * (k + 1.0) / (6.0 * blocks as f64)).sqrt())
+}
+
+/**
+ * Function to get the score of a fitness, so the higher the better in every problem. A NaN fitness is the worst one.
+ */
+fn score(fitness: f64, limit_configuration: &LimitConfiguration) -> f64 {
+    let score = match limit_configuration.problem_solving {
+        ProblemSolving::Maximization => fitness,
+        ProblemSolving::Minimization => -fitness,
+        ProblemSolving::FixedFitness => -(limit_configuration.fitness_target.unwrap_or(0.0) - fitness).abs(),
+    };
+    if score.is_nan() {f64::NEG_INFINITY} else {score}
+}
+
+/**
+ * Function to get the magnitude of an A12 effect size
+ */
+fn effect_size(a12: f64) -> &'static str {
+    let distance = (a12 - 0.5).abs();
+    if distance < 0.06 {"Negligible"} else if distance < 0.14 {"Small"} else if distance < 0.21 {"Medium"} else {"Large"}
+}
+
+/**
+ * Function to rank values in ascending order, where the ties get the average rank. It also returns the sum of t^3 - t over the groups of t ties.
+ */
+fn ranks(values: &[f64]) -> (Vec<f64>, f64) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let (mut ranks, mut ties) = (vec![0.0; values.len()], 0.0);
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]].total_cmp(&values[order[start]]).is_eq() {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        order[start..end].iter().for_each(|index| ranks[*index] = rank);
+        let group = (end - start) as f64;
+        ties += group.powi(3) - group;
+        start = end;
+    }
+    (ranks, ties)
+}
+
+/**
+ * Function to get the probability that a standard normal variable is greater than z
+ */
+fn normal_upper_tail(z: f64) -> f64 {
+    if z < 0.0 {
+        1.0 - normal_upper_tail(-z)
+    } else {
+        0.5 * regularized_gamma_upper(0.5, z * z / 2.0)
+    }
+}
+
+/**
+ * Function to get the logarithm of the gamma function, with the Lanczos approximation
+ */
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [76.18009172947146, -86.50532032941677, 24.01409824083091, -1.231739572450155, 0.1208650973866179e-2, -0.5395239384953e-5];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let series = COEFFICIENTS.iter().enumerate().fold(1.000000000190015, |sum, (i, coefficient)| sum + coefficient / (x + 1.0 + i as f64));
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/**
+ * Function to get the regularized upper incomplete gamma function Q(a, x), with a series or a continued fraction
+ */
+fn regularized_gamma_upper(a: f64, x: f64) -> f64 {
+    if x <= 0.0 || a <= 0.0 {
+        return 1.0;
+    }
+    let prefix = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        //Series of P(a, x)
+        let (mut term, mut sum, mut denominator) = (1.0 / a, 1.0 / a, a);
+        for _ in 0..500 {
+            denominator += 1.0;
+            term *= x / denominator;
+            sum += term;
+            if term.abs() < sum.abs() * 1e-15 {
+                break;
+            }
+        }
+        (1.0 - sum * prefix).clamp(0.0, 1.0)
+    } else {
+        //Continued fraction of Q(a, x) with the modified Lentz method
+        let tiny = 1e-300;
+        let mut b = x + 1.0 - a;
+        let (mut c, mut d) = (1.0 / tiny, 1.0 / b);
+        let mut h = d;
+        for i in 1..500 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            d = if d.abs() < tiny {tiny} else {d};
+            c = b + an / c;
+            c = if c.abs() < tiny {tiny} else {c};
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        (prefix * h).clamp(0.0, 1.0)
+    }
+}
@@ -1,9 +1,12 @@
 use std::fmt;
+#[cfg(feature = "serde")]
+use std::{fs, path::Path};
 
-use crate::{operations::{Crossover, Selection, Mutation, Survivor}, traits::ConfigurationT};
+use crate::{operations::{Crossover, Selection, Mutation, Survivor, OperatorSelection, Replacement, Niching}, traits::ConfigurationT, helpers};
 
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProblemSolving {
     Minimization,
     Maximization,
@@ -20,6 +23,7 @@ impl fmt::Display for ProblemSolving {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LogLevel {
     Off,
     Error,
@@ -29,7 +33,35 @@ pub enum LogLevel {
     Trace,
 }
 
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Schedule {
+    Constant,
+    Linear,
+    Exponential{rate: f64},
+    Cosine,
+    Step{step_size: i32, factor: f64},
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AgaFormulation {
+    Simplified,
+    SrinivasPatnaik,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Distance {
+    Genotype,
+    Hamming,
+    Euclidean,
+    KendallTau,
+}
+
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct SelectionConfiguration{
     pub number_of_couples: i32,
     pub method: Selection,
@@ -44,11 +76,14 @@ impl Default for SelectionConfiguration{
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct CrossoverConfiguration{
     pub number_of_points: Option<i32>,
     pub probability_max: Option<f64>,
     pub probability_min: Option<f64>,
     pub method: Crossover,
+    pub schedule: Schedule,
 }
 impl Default for CrossoverConfiguration{
     fn default() -> Self {
@@ -56,16 +91,21 @@ impl Default for CrossoverConfiguration{
             number_of_points: None, 
             probability_max: None, 
             probability_min: None,
-            method: Crossover::Uniform
+            method: Crossover::Uniform,
+            schedule: Schedule::Constant,
         }
     }
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct MutationConfiguration{
     pub probability_max: Option<f64>,
     pub probability_min: Option<f64>,
     pub method: Mutation,
+    pub schedule: Schedule,
+    pub step_size: Option<f64>,
 }
 impl Default for MutationConfiguration {
     fn default() -> Self {
@@ -73,12 +113,16 @@ impl Default for MutationConfiguration {
             probability_max: None,
             probability_min: None,
             method: Mutation::Swap, 
+            schedule: Schedule::Constant,
+            step_size: None,
         }
     }
 }
 
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct LimitConfiguration{
     pub problem_solving: ProblemSolving,
     pub max_generations: i32,
@@ -105,48 +149,277 @@ impl Default for LimitConfiguration {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct OperatorSelectionConfiguration{
+    pub method: Option<OperatorSelection>,
+    pub crossover_operators: Vec<Crossover>,
+    pub mutation_operators: Vec<Mutation>,
+    pub probability_min: f64,
+    pub adaptation_rate: f64,
+    pub learning_rate: f64,
+    pub exploration: f64,
+}
+impl Default for OperatorSelectionConfiguration {
+    fn default() -> Self {
+        OperatorSelectionConfiguration { 
+            method: None, 
+            crossover_operators: Vec::new(), 
+            mutation_operators: Vec::new(), 
+            probability_min: 0.05, 
+            adaptation_rate: 0.3, 
+            learning_rate: 0.8, 
+            exploration: 1.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct SteadyStateConfiguration{
+    pub steady_state: bool,
+    pub offspring_per_step: i32,
+    pub replacement: Replacement,
+}
+impl Default for SteadyStateConfiguration {
+    fn default() -> Self {
+        SteadyStateConfiguration { 
+            steady_state: false, 
+            offspring_per_step: 2, 
+            replacement: Replacement::Worst,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct NichingConfiguration{
+    pub method: Option<Niching>,
+    pub sigma_share: f64,
+    pub alpha: f64,
+    pub niche_capacity: i32,
+    pub window_size: i32,
+}
+impl Default for NichingConfiguration {
+    fn default() -> Self {
+        NichingConfiguration { 
+            method: None,
+            sigma_share: 1.0,
+            alpha: 1.0,
+            niche_capacity: 1,
+            window_size: 20,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct StatisticsConfiguration{
+    pub diversity: bool,
+    pub distance: Distance,
+}
+impl Default for StatisticsConfiguration {
+    fn default() -> Self {
+        StatisticsConfiguration { 
+            diversity: false,
+            distance: Distance::Genotype,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct SaveProgressConfiguration{
     pub save_progress: bool,
     pub save_progress_interval: i32,
     pub save_progress_path: String,
 }
-impl Default for SaveProgressConfiguration {
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HistoryFormat {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct HistoryConfiguration{
+    pub export_history: bool,
+    pub history_path: String,
+    pub history_format: HistoryFormat,
+    pub best_individual_path: Option<String>,
+}
+impl Default for HistoryConfiguration {
     fn default() -> Self {
-        SaveProgressConfiguration { 
-            save_progress: false, 
-            save_progress_interval: 0, 
-            save_progress_path: String::new()
+        HistoryConfiguration { 
+            export_history: false,
+            history_path: String::new(),
+            history_format: HistoryFormat::Csv,
+            best_individual_path: None,
         }
     }
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
 pub struct GaConfiguration {
     pub adaptive_ga: bool,
+    pub aga_formulation: AgaFormulation,
     pub number_of_threads: i32,
     pub limit_configuration: LimitConfiguration,
     pub selection_configuration: SelectionConfiguration,
     pub crossover_configuration: CrossoverConfiguration,
     pub mutation_configuration: MutationConfiguration,
+    pub operator_selection_configuration: OperatorSelectionConfiguration,
+    pub steady_state_configuration: SteadyStateConfiguration,
     pub survivor: Survivor,
+    pub lambda: Option<i32>,
+    pub fitness_cache_size: Option<i32>,
+    pub niching_configuration: NichingConfiguration,
     pub log_level: LogLevel,
+    pub statistics_configuration: StatisticsConfiguration,
     pub save_progress_configuration: SaveProgressConfiguration,
+    pub history_configuration: HistoryConfiguration,
 }
 impl Default for GaConfiguration{
     fn default() -> Self {
         GaConfiguration { 
             adaptive_ga: false, 
+            aga_formulation: AgaFormulation::Simplified,
             number_of_threads: 1, 
             survivor: Survivor::Fitness, 
+            lambda: None,
+            fitness_cache_size: None,
+            niching_configuration: NichingConfiguration { ..Default::default() },
             log_level: LogLevel::Off,
             limit_configuration: LimitConfiguration { ..Default::default() }, 
             selection_configuration: SelectionConfiguration { ..Default::default() }, 
             crossover_configuration: CrossoverConfiguration { ..Default::default() }, 
             mutation_configuration: MutationConfiguration { ..Default::default() },
-            save_progress_configuration: SaveProgressConfiguration { ..Default::default() }
+            operator_selection_configuration: OperatorSelectionConfiguration { ..Default::default() },
+            steady_state_configuration: SteadyStateConfiguration { ..Default::default() },
+            statistics_configuration: StatisticsConfiguration { ..Default::default() },
+            save_progress_configuration: SaveProgressConfiguration { ..Default::default() },
+            history_configuration: HistoryConfiguration { ..Default::default() },
+        }
+    }
+}
+
+/**
+ * Error of the loading or the validation of a configuration
+ */
+#[derive(Debug)]
+pub enum ConfigurationError {
+    //The configuration file can't be read or written
+    Io(std::io::Error),
+    //The content is not a valid configuration: syntax errors, unknown keys, unknown operators or wrong types
+    Parse(String),
+    //A value of the configuration is not valid
+    Invalid(String),
+}
+impl fmt::Display for ConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigurationError::Io(error) => write!(f, "The configuration file can't be accessed: {}", error),
+            ConfigurationError::Parse(message) => write!(f, "The configuration can't be parsed: {}", message),
+            ConfigurationError::Invalid(message) => write!(f, "The configuration is not valid: {}", message),
         }
     }
 }
+impl std::error::Error for ConfigurationError {}
+impl From<std::io::Error> for ConfigurationError {
+    fn from(error: std::io::Error) -> Self {
+        ConfigurationError::Io(error)
+    }
+}
+
+/**
+ * Checks that an optional probability is between 0.0 and 1.0
+ */
+fn check_probability(probability: Option<f64>, name: &str) -> Result<(), ConfigurationError>{
+    match probability {
+        Some(probability) if !(0.0..=1.0).contains(&probability) => 
+            Err(ConfigurationError::Invalid(format!("The {} must be between 0.0 and 1.0, and it is {}.", name, probability))),
+        _ => Ok(()),
+    }
+}
+
+impl GaConfiguration {
+    /**
+     * Validates the values of the configuration that don't depend on the population or the alleles
+     */
+    pub fn validate(&self) -> Result<(), ConfigurationError>{
+        let invalid = |message: &str| Err(ConfigurationError::Invalid(message.to_string()));
+
+        //1- Limits and threads
+        if self.number_of_threads <= 0 {
+            return invalid("The number of threads must be greater than 0.");
+        }
+        if self.limit_configuration.max_generations <= 0 {
+            return invalid("The max_generations in the limit_configuration must be greater than 0.");
+        }
+
+        //2- Crossover and mutation probabilities
+        check_probability(self.crossover_configuration.probability_max, "probability_max in the crossover_configuration")?;
+        check_probability(self.crossover_configuration.probability_min, "probability_min in the crossover_configuration")?;
+        check_probability(self.mutation_configuration.probability_max, "probability_max in the mutation_configuration")?;
+        check_probability(self.mutation_configuration.probability_min, "probability_min in the mutation_configuration")?;
+
+        //3- The same conditions checked before the run, with the population size of the configuration
+        helpers::configuration_checker(self, self.limit_configuration.population_size).map_err(ConfigurationError::Invalid)
+    }
+
+    /**
+     * Loads and validates a configuration from a TOML string. The keys that are not set take their default values.
+     */
+    #[cfg(feature = "serde")]
+    pub fn from_toml_str(toml: &str) -> Result<Self, ConfigurationError>{
+        let configuration: GaConfiguration = toml::from_str(toml).map_err(|error| ConfigurationError::Parse(error.to_string()))?;
+        configuration.validate()?;
+        Ok(configuration)
+    }
+
+    /**
+     * Loads and validates a configuration from a JSON string. The keys that are not set take their default values.
+     */
+    #[cfg(feature = "serde")]
+    pub fn from_json_str(json: &str) -> Result<Self, ConfigurationError>{
+        let configuration: GaConfiguration = serde_json::from_str(json).map_err(|error| ConfigurationError::Parse(error.to_string()))?;
+        configuration.validate()?;
+        Ok(configuration)
+    }
+
+    /**
+     * Loads and validates a configuration from a TOML file
+     */
+    #[cfg(feature = "serde")]
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigurationError>{
+        Self::from_toml_str(&fs::read_to_string(path)?)
+    }
+
+    /**
+     * Loads and validates a configuration from a JSON file
+     */
+    #[cfg(feature = "serde")]
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigurationError>{
+        Self::from_json_str(&fs::read_to_string(path)?)
+    }
+
+    /**
+     * Serializes the configuration to TOML. The result can be loaded again with `from_toml_str`.
+     */
+    #[cfg(feature = "serde")]
+    pub fn to_toml(&self) -> Result<String, ConfigurationError>{
+        toml::to_string(self).map_err(|error| ConfigurationError::Parse(error.to_string()))
+    }
+}
 
 impl ConfigurationT for GaConfiguration{
     fn new() -> Self {
@@ -156,6 +429,10 @@ impl ConfigurationT for GaConfiguration{
         self.adaptive_ga = adaptive_ga;
         self
     }
+    fn with_aga_formulation(&mut self, aga_formulation: AgaFormulation) -> &mut Self{
+        self.aga_formulation = aga_formulation;
+        self
+    }
     fn with_threads(&mut self, number_of_threads: i32)-> &mut Self{
         self.number_of_threads = number_of_threads;
         self
@@ -168,6 +445,36 @@ impl ConfigurationT for GaConfiguration{
         self.survivor = method;
         self
     }
+    fn with_lambda(&mut self, lambda: i32) -> &mut Self{
+        self.lambda = Some(lambda);
+        self
+    }
+    fn with_fitness_cache(&mut self, fitness_cache_size: i32) -> &mut Self{
+        self.fitness_cache_size = Some(fitness_cache_size);
+        self
+    }
+
+    //Niching configuration
+    fn with_niching_method(&mut self, method: Niching) -> &mut Self{
+        self.niching_configuration.method = Some(method);
+        self
+    }
+    fn with_sigma_share(&mut self, sigma_share: f64) -> &mut Self{
+        self.niching_configuration.sigma_share = sigma_share;
+        self
+    }
+    fn with_sharing_alpha(&mut self, alpha: f64) -> &mut Self{
+        self.niching_configuration.alpha = alpha;
+        self
+    }
+    fn with_niche_capacity(&mut self, niche_capacity: i32) -> &mut Self{
+        self.niching_configuration.niche_capacity = niche_capacity;
+        self
+    }
+    fn with_window_size(&mut self, window_size: i32) -> &mut Self{
+        self.niching_configuration.window_size = window_size;
+        self
+    }
 
     //Limit configuration
     fn with_problem_solving(&mut self, problem_solving: ProblemSolving)->&mut Self{
@@ -230,6 +537,10 @@ impl ConfigurationT for GaConfiguration{
         self.crossover_configuration.method = method;
         self
     }
+    fn with_crossover_schedule(&mut self, schedule: Schedule) -> &mut Self {
+        self.crossover_configuration.schedule = schedule;
+        self
+    }
 
     //Mutation configuration
     fn with_mutation_probability_max(&mut self, probability_max: f64)->&mut Self{
@@ -244,6 +555,68 @@ impl ConfigurationT for GaConfiguration{
         self.mutation_configuration.method = method;
         self
     }
+    fn with_mutation_schedule(&mut self, schedule: Schedule) -> &mut Self {
+        self.mutation_configuration.schedule = schedule;
+        self
+    }
+    fn with_mutation_step_size(&mut self, step_size: f64) -> &mut Self {
+        self.mutation_configuration.step_size = Some(step_size);
+        self
+    }
+
+    //Operator selection configuration
+    fn with_operator_selection_method(&mut self, method: OperatorSelection) -> &mut Self {
+        self.operator_selection_configuration.method = Some(method);
+        self
+    }
+    fn with_crossover_operators(&mut self, operators: Vec<Crossover>) -> &mut Self {
+        self.operator_selection_configuration.crossover_operators = operators;
+        self
+    }
+    fn with_mutation_operators(&mut self, operators: Vec<Mutation>) -> &mut Self {
+        self.operator_selection_configuration.mutation_operators = operators;
+        self
+    }
+    fn with_operator_selection_probability_min(&mut self, probability_min: f64) -> &mut Self {
+        self.operator_selection_configuration.probability_min = probability_min;
+        self
+    }
+    fn with_operator_selection_adaptation_rate(&mut self, adaptation_rate: f64) -> &mut Self {
+        self.operator_selection_configuration.adaptation_rate = adaptation_rate;
+        self
+    }
+    fn with_operator_selection_learning_rate(&mut self, learning_rate: f64) -> &mut Self {
+        self.operator_selection_configuration.learning_rate = learning_rate;
+        self
+    }
+    fn with_operator_selection_exploration(&mut self, exploration: f64) -> &mut Self {
+        self.operator_selection_configuration.exploration = exploration;
+        self
+    }
+
+    //Steady state configuration
+    fn with_steady_state(&mut self, steady_state: bool) -> &mut Self {
+        self.steady_state_configuration.steady_state = steady_state;
+        self
+    }
+    fn with_offspring_per_step(&mut self, offspring_per_step: i32) -> &mut Self {
+        self.steady_state_configuration.offspring_per_step = offspring_per_step;
+        self
+    }
+    fn with_replacement_method(&mut self, method: Replacement) -> &mut Self {
+        self.steady_state_configuration.replacement = method;
+        self
+    }
+
+    //Statistics configuration
+    fn with_diversity_statistics(&mut self, diversity: bool) -> &mut Self {
+        self.statistics_configuration.diversity = diversity;
+        self
+    }
+    fn with_distance_metric(&mut self, distance: Distance) -> &mut Self {
+        self.statistics_configuration.distance = distance;
+        self
+    }
 
     //Save progress configuration
     fn with_save_progress(&mut self, save_progress: bool) -> &mut Self {
@@ -259,4 +632,22 @@ impl ConfigurationT for GaConfiguration{
         self
     }
 
+    //History configuration
+    fn with_export_history(&mut self, export_history: bool) -> &mut Self {
+        self.history_configuration.export_history = export_history;
+        self
+    }
+    fn with_history_path(&mut self, history_path: String) -> &mut Self {
+        self.history_configuration.history_path = history_path;
+        self
+    }
+    fn with_history_format(&mut self, history_format: HistoryFormat) -> &mut Self {
+        self.history_configuration.history_format = history_format;
+        self
+    }
+    fn with_best_individual_path(&mut self, best_individual_path: String) -> &mut Self {
+        self.history_configuration.best_individual_path = Some(best_individual_path);
+        self
+    }
+
 } 
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use crate::{configuration::Distance, traits::{GenotypeT, GeneT}};
+
+/**
+ * Function to calculate the distance between two genotypes with the given metric
+ */
+pub fn factory<U: GenotypeT>(distance: Distance, individual_1: &U, individual_2: &U) -> f64{
+    match distance {
+        Distance::Genotype => {individual_1.get_distance(individual_2)},
+        Distance::Hamming => {hamming(individual_1, individual_2)},
+        Distance::Euclidean => {euclidean(individual_1, individual_2)},
+        Distance::KendallTau => {kendall_tau(individual_1, individual_2)},
+    }
+}
+
+/**
+ * Function to calculate the Hamming distance over the gene ids: the number of loci with different genes.
+ * The genes missing in the shortest genotype count as different.
+ */
+pub fn hamming<U: GenotypeT>(individual_1: &U, individual_2: &U) -> f64{
+    let (dna_1, dna_2) = (individual_1.get_dna(), individual_2.get_dna());
+    let different_genes = dna_1.iter().zip(dna_2).filter(|(gene_1, gene_2)| gene_1.get_id() != gene_2.get_id()).count();
+    (different_genes + dna_1.len().abs_diff(dna_2.len())) as f64
+}
+
+/**
+ * Function to calculate the Euclidean distance over the numeric values of the genes
+ */
+pub fn euclidean<U: GenotypeT>(individual_1: &U, individual_2: &U) -> f64{
+    individual_1.get_dna().iter().zip(individual_2.get_dna())
+        .map(|(gene_1, gene_2)| (gene_1.get_value() - gene_2.get_value()).powi(2))
+        .sum::<f64>().sqrt()
+}
+
+/**
+ * Function to calculate the Kendall tau distance between two permutations of the gene ids: 
+ * the number of pairs of genes in a different order.
+ */
+pub fn kendall_tau<U: GenotypeT>(individual_1: &U, individual_2: &U) -> f64{
+
+    //1- Position of each gene in the second permutation
+    let positions: HashMap<i32, usize> = individual_2.get_dna().iter().enumerate().map(|(position, gene)| (gene.get_id(), position)).collect();
+    let permutation: Vec<usize> = individual_1.get_dna().iter().map(|gene| {
+        match positions.get(&gene.get_id()) {
+            Some(position) => *position,
+            None => panic!("The Kendall tau distance needs both genotypes to be permutations of the same genes."),
+        }
+    }).collect();
+
+    //2- Number of pairs in a different order
+    let mut discordant_pairs = 0;
+    for i in 0..permutation.len() {
+        for j in i + 1..permutation.len() {
+            if permutation[i] > permutation[j] {
+                discordant_pairs += 1;
+            }
+        }
+    }
+    discordant_pairs as f64
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use rand::Rng;
+use crate::traits::GeneT;
+
+/**
+ * Domain of the values of a locus, for the random initialization and the mutations
+ */
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Domain<G> {
+    //Set of genes, such as categories. The genes are taken as they are.
+    Discrete(Vec<G>),
+    //Integer values between the minimum and the maximum, both included, set with the value of the gene
+    Integer(i64, i64),
+    //Real values between the minimum and the maximum, set with the value of the gene
+    Real(f64, f64),
+}
+
+impl<G: GeneT> Domain<G> {
+    /**
+     * Function to get a random gene of the domain
+     */
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> G {
+        match self {
+            Domain::Discrete(genes) => genes[rng.gen_range(0..genes.len())].clone(),
+            Domain::Integer(min, max) => {
+                let mut gene = G::new();
+                gene.set_value(rng.gen_range(*min..=*max) as f64);
+                gene
+            },
+            Domain::Real(min, max) => {
+                let mut gene = G::new();
+                gene.set_value(if min < max {rng.gen_range(*min..*max)} else {*min});
+                gene
+            },
+        }
+    }
+
+    /**
+     * Function to get the gene at a quantile of the domain, between 0 and 1
+     */
+    pub fn quantile(&self, quantile: f64) -> G {
+        match self {
+            Domain::Discrete(genes) => genes[((quantile * genes.len() as f64) as usize).min(genes.len() - 1)].clone(),
+            Domain::Integer(min, max) => {
+                let span = (max - min + 1) as f64;
+                let mut gene = G::new();
+                gene.set_value((*min + (quantile * span) as i64).min(*max) as f64);
+                gene
+            },
+            Domain::Real(min, max) => {
+                let mut gene = G::new();
+                gene.set_value(min + quantile * (max - min));
+                gene
+            },
+        }
+    }
+
+    /**
+     * Function to get the opposite gene within the domain: the value reflected over the centre of the ranges,
+     * or the gene in the reflected position for the discrete domains
+     */
+    pub fn opposite(&self, gene: &G) -> G {
+        let value = gene.get_value();
+        match self {
+            Domain::Discrete(genes) => {
+                let index = genes.iter().enumerate()
+                    .min_by(|(_, a), (_, b)| (a.get_value() - value).abs().total_cmp(&(b.get_value() - value).abs())).unwrap().0;
+                genes[genes.len() - 1 - index].clone()
+            },
+            Domain::Integer(min, max) => {
+                let mut opposite = gene.clone();
+                opposite.set_value((min + max) as f64 - value.round());
+                opposite
+            },
+            Domain::Real(min, max) => {
+                let mut opposite = gene.clone();
+                opposite.set_value(min + max - value);
+                opposite
+            },
+        }
+    }
+
+    /**
+     * Function to bring a gene back to the domain: the values of the ranges are rounded and clamped,
+     * and the discrete domains take the gene with the closest value
+     */
+    pub fn repair(&self, gene: &mut G) {
+        let value = gene.get_value();
+        match self {
+            Domain::Discrete(genes) => {
+                let closest = genes.iter().min_by(|a, b| (a.get_value() - value).abs().total_cmp(&(b.get_value() - value).abs())).unwrap();
+                if closest.get_value() != value {
+                    *gene = closest.clone();
+                }
+            },
+            Domain::Integer(min, max) => {
+                gene.set_value(value.round().clamp(*min as f64, *max as f64));
+            },
+            Domain::Real(min, max) => {
+                gene.set_value(value.clamp(*min, *max));
+            },
+        }
+    }
+
+    /**
+     * Function to check if the domain has at least one value
+     */
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Domain::Discrete(genes) => genes.is_empty(),
+            Domain::Integer(min, max) => min > max,
+            Domain::Real(min, max) => !(min.is_finite() && max.is_finite() && min <= max),
+        }
+    }
+}
+
+/**
+ * Function to get the domain of a locus. A single domain is shared by every locus.
+ */
+pub fn locus_domain<G>(domains: &[Domain<G>], locus: usize) -> &Domain<G> {
+    if domains.len() == 1 {&domains[0]} else {&domains[locus]}
+}
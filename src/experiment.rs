@@ -0,0 +1,326 @@
+use std::{sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}}, thread};
+use log::{info, debug};
+use crate::{configuration::{GaConfiguration, ProblemSolving}, ga::Ga, population::Population, traits::{GenotypeT, ConfigurationT}, statistics::{GenerationStatistics, Summary}, rng, domain::Domain, initialization::Initializer};
+
+/**
+ * Named override of the parameters of the base configuration
+ */
+#[derive(Clone)]
+pub struct Parameter {
+    pub name: String,
+    apply: Arc<dyn Fn(&mut GaConfiguration) + Send + Sync>,
+}
+
+impl Parameter {
+    /**
+     * Creates a parameter override from a function that modifies the configuration
+     */
+    pub fn new<F>(name: &str, apply: F) -> Self
+    where F: Fn(&mut GaConfiguration) + Send + Sync + 'static {
+        Parameter { name: name.to_string(), apply: Arc::new(apply) }
+    }
+}
+
+/**
+ * Result of an independent run
+ */
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunResult {
+    //Seed of the random numbers of the run
+    pub seed: u64,
+    //Fitness of the best individual of the run
+    pub best_fitness: f64,
+    //Number of fitness evaluations of the run
+    pub evaluations: usize,
+    //Number of fitness evaluations until the success target was reached, if it was
+    pub evaluations_to_success: Option<usize>,
+    //Statistics of each generation of the run
+    pub statistics: Vec<GenerationStatistics>,
+}
+
+/**
+ * Aggregated results of the runs of a configuration
+ */
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExperimentSummary {
+    //Summary of the best fitness of the runs
+    pub best_fitness: Summary,
+    //Summary of the best fitness so far of the runs in each generation. The runs that stopped earlier keep their last value.
+    pub convergence: Vec<Summary>,
+    //Rate of runs reaching the success target, if there is a target
+    pub success_rate: Option<f64>,
+    //Expected number of fitness evaluations to reach the success target, if there is a target. It's infinite if no run reaches it.
+    pub expected_running_time: Option<f64>,
+}
+
+/**
+ * Runs and summary of a configuration of the experiment
+ */
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigurationResult {
+    //Names of the parameter overrides of the configuration, or "base" without overrides
+    pub name: String,
+    pub configuration: GaConfiguration,
+    pub runs: Vec<RunResult>,
+    pub summary: ExperimentSummary,
+}
+
+/**
+ * Experiment running several independent runs of each configuration of a parameter sweep
+ */
+pub struct Experiment<U>
+where
+    U: GenotypeT
+{
+    //Base configuration, modified by the parameter overrides of each variant
+    pub configuration: GaConfiguration,
+    //Parameter overrides of each configuration. Without variants, only the base configuration is run.
+    pub variants: Vec<Vec<Parameter>>,
+    //Alleles for the random initialization of the population
+    pub alleles: Vec<U::Gene>,
+    //Domains of the loci for the random initialization and the mutations, instead of the alleles
+    pub domains: Vec<Domain<U::Gene>>,
+    //Strategy of the random initialization of every run
+    pub initializer: Option<Arc<dyn Initializer<U>>>,
+    //Individual cloned for the random initialization of every run, instead of a new one
+    pub prototype: Option<U>,
+    //Initial population of every run, instead of the random initialization
+    pub individuals: Option<Vec<U>>,
+    //Number of independent runs of each configuration
+    pub runs: usize,
+    //Seed of the first run. Run i of every configuration uses the seed + i.
+    pub seed: u64,
+    //Number of runs executed at the same time
+    pub parallel_runs: usize,
+    //Fitness to reach for a run to be successful. If it is not set, the fitness target of the configuration is used.
+    pub success_target: Option<f64>,
+}
+
+impl<U> Experiment<U>
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    /**
+     * Creates an experiment from a base configuration, with 30 sequential runs
+     */
+    pub fn new(configuration: GaConfiguration) -> Self {
+        Experiment { configuration, variants: Vec::new(), alleles: Vec::new(), domains: Vec::new(), initializer: None, prototype: None, individuals: None, runs: 30, seed: 0, parallel_runs: 1, success_target: None }
+    }
+
+    /**
+     * Function to set the alleles for the random initialization of the population
+     */
+    pub fn with_alleles(&mut self, alleles: Vec<U::Gene>) -> &mut Self {
+        self.alleles = alleles;
+        self
+    }
+
+    /**
+     * Function to set the domains of the loci
+     */
+    pub fn with_domains(&mut self, domains: Vec<Domain<U::Gene>>) -> &mut Self {
+        self.domains = domains;
+        self
+    }
+
+    /**
+     * Function to set the strategy of the random initialization of every run
+     */
+    pub fn with_initializer<I: Initializer<U> + 'static>(&mut self, initializer: I) -> &mut Self {
+        self.initializer = Some(Arc::new(initializer));
+        self
+    }
+
+    /**
+     * Function to set the individual cloned for the random initialization of every run, instead of a new one
+     */
+    pub fn with_prototype(&mut self, prototype: U) -> &mut Self {
+        self.prototype = Some(prototype);
+        self
+    }
+
+    /**
+     * Function to set the initial population of every run
+     */
+    pub fn with_population(&mut self, population: Population<U>) -> &mut Self {
+        self.individuals = Some(population.individuals);
+        self
+    }
+
+    /**
+     * Function to add a configuration with the given parameter overrides
+     */
+    pub fn with_variant(&mut self, parameters: Vec<Parameter>) -> &mut Self {
+        self.variants.push(parameters);
+        self
+    }
+
+    /**
+     * Function to add a configuration for every combination of the values of the parameters
+     */
+    pub fn with_grid(&mut self, parameters: Vec<Vec<Parameter>>) -> &mut Self {
+        let mut combinations: Vec<Vec<Parameter>> = vec![Vec::new()];
+        for values in parameters.iter().filter(|values| !values.is_empty()) {
+            combinations = combinations.into_iter().flat_map(|combination| values.iter().map(move |value| {
+                let mut combination = combination.clone();
+                combination.push(value.clone());
+                combination
+            })).collect();
+        }
+        self.variants.extend(combinations.into_iter().filter(|combination| !combination.is_empty()));
+        self
+    }
+
+    /**
+     * Function to set the number of independent runs of each configuration
+     */
+    pub fn with_runs(&mut self, runs: usize) -> &mut Self {
+        self.runs = runs;
+        self
+    }
+
+    /**
+     * Function to set the seed of the first run
+     */
+    pub fn with_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /**
+     * Function to set the number of runs executed at the same time
+     */
+    pub fn with_parallel_runs(&mut self, parallel_runs: usize) -> &mut Self {
+        self.parallel_runs = parallel_runs;
+        self
+    }
+
+    /**
+     * Function to set the fitness to reach for a run to be successful
+     */
+    pub fn with_success_target(&mut self, success_target: f64) -> &mut Self {
+        self.success_target = Some(success_target);
+        self
+    }
+
+    /**
+     * Runs the experiment, returning the runs and the summary of each configuration
+     */
+    pub fn run(&self) -> Vec<ConfigurationResult> {
+        if self.runs == 0 || self.parallel_runs == 0 {
+            panic!("The experiment needs at least one run and one parallel run.");
+        }
+
+        //1- Configurations of the experiment
+        let configurations: Vec<(String, GaConfiguration)> = if self.variants.is_empty() {
+            vec![(String::from("base"), self.configuration.clone())]
+        } else {
+            self.variants.iter().map(|parameters| {
+                let mut configuration = self.configuration.clone();
+                parameters.iter().for_each(|parameter| (parameter.apply)(&mut configuration));
+                (parameters.iter().map(|parameter| parameter.name.as_str()).collect::<Vec<&str>>().join(", "), configuration)
+            }).collect()
+        };
+        info!(target="experiment_events", method="run"; "Experiment started with {} configurations and {} runs", configurations.len(), self.runs);
+
+        //2- Runs of every configuration, shared between the parallel runs
+        let jobs = configurations.len() * self.runs;
+        let results: Mutex<Vec<Option<RunResult>>> = Mutex::new(vec![None; jobs]);
+        let next_job = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..self.parallel_runs.min(jobs) {
+                scope.spawn(|| loop {
+                    let job = next_job.fetch_add(1, Ordering::Relaxed);
+                    if job >= jobs {
+                        break;
+                    }
+                    let result = self.run_once(&configurations[job / self.runs].1, self.seed + (job % self.runs) as u64);
+                    results.lock().unwrap()[job] = Some(result);
+                });
+            }
+        });
+
+        //3- Summary of every configuration
+        let mut results = results.into_inner().unwrap().into_iter().map(|result| result.unwrap());
+        configurations.into_iter().map(|(name, configuration)| {
+            let runs: Vec<RunResult> = results.by_ref().take(self.runs).collect();
+            let summary = summarize(&runs, self.success_target.or(configuration.limit_configuration.fitness_target).is_some());
+            debug!(target="experiment_events", method="run"; "Configuration {} - mean best fitness {}", name, summary.best_fitness.mean);
+            ConfigurationResult { name, configuration, runs, summary }
+        }).collect()
+    }
+
+    /**
+     * Executes a seeded run of a configuration
+     */
+    fn run_once(&self, configuration: &GaConfiguration, seed: u64) -> RunResult {
+        rng::set_seed(Some(seed));
+
+        let mut ga = Ga::<U>::new();
+        ga.configuration = configuration.clone();
+        ga.with_alleles(self.alleles.clone()).with_domains(self.domains.clone());
+        ga.initializer = self.initializer.clone();
+        ga.prototype = self.prototype.clone();
+        if let Some(individuals) = &self.individuals {
+            ga.with_population(Population::new(individuals.clone()));
+        }
+        let population = ga.run();
+        rng::set_seed(None);
+
+        //The success is checked over the best fitness so far of each generation
+        let limit_configuration = configuration.limit_configuration;
+        let success_target = self.success_target.or(limit_configuration.fitness_target);
+        let statistics = population.statistics;
+        let evaluations_to_success = success_target.and_then(|target| statistics.iter()
+            .find(|generation| target_reached(generation.best_so_far, target, limit_configuration.problem_solving))
+            .map(|generation| generation.evaluations));
+
+        RunResult {
+            seed,
+            best_fitness: statistics.last().map_or(f64::NAN, |generation| generation.best_so_far),
+            evaluations: statistics.last().map_or(0, |generation| generation.evaluations),
+            evaluations_to_success,
+            statistics,
+        }
+    }
+}
+
+/**
+ * Function to check if a fitness reaches the target, depending on the problem
+ */
+fn target_reached(fitness: f64, target: f64, problem_solving: ProblemSolving) -> bool {
+    match problem_solving {
+        ProblemSolving::Maximization => fitness >= target,
+        ProblemSolving::Minimization => fitness <= target,
+        ProblemSolving::FixedFitness => fitness == target,
+    }
+}
+
+/**
+ * Function to summarize the runs of a configuration
+ */
+fn summarize(runs: &[RunResult], has_target: bool) -> ExperimentSummary {
+    let best_fitness = Summary::new(&runs.iter().map(|run| run.best_fitness).collect::<Vec<f64>>());
+
+    //The convergence curves are aligned by generation
+    let generations = runs.iter().map(|run| run.statistics.len()).max().unwrap_or(0);
+    let convergence = (0..generations).map(|generation| {
+        let values: Vec<f64> = runs.iter().filter_map(|run| run.statistics.get(generation).or(run.statistics.last()).map(|statistics| statistics.best_so_far)).collect();
+        Summary::new(&values)
+    }).collect();
+
+    //The expected running time counts all the evaluations of the unsuccessful runs
+    let (success_rate, expected_running_time) = if has_target {
+        let successes = runs.iter().filter(|run| run.evaluations_to_success.is_some()).count();
+        let evaluations: usize = runs.iter().map(|run| run.evaluations_to_success.unwrap_or(run.evaluations)).sum();
+        let expected_running_time = if successes == 0 {f64::INFINITY} else {evaluations as f64 / successes as f64};
+        (Some(successes as f64 / runs.len() as f64), Some(expected_running_time))
+    } else {
+        (None, None)
+    };
+
+    ExperimentSummary { best_fitness, convergence, success_rate, expected_running_time }
+}
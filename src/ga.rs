@@ -1,8 +1,8 @@
-use std::{sync::{mpsc::sync_channel, Mutex, Arc}, thread, collections::HashMap};
-use rand::Rng;
-use log::{trace, debug, info};
+use std::{sync::{Arc, Mutex}, collections::HashMap, cmp::Ordering, time::Instant};
+use rand::{Rng, seq::SliceRandom};
+use log::{trace, debug, info, error};
 use std::env;
-use crate::{population::Population, traits::{GenotypeT, ConfigurationT}, operations::{selection, crossover, mutation, survivor}, configuration::{ProblemSolving, LimitConfiguration, LogLevel}, helpers::{condition_checker_factory, self}};
+use crate::{population::Population, traits::{GenotypeT, ConfigurationT}, operations::{Survivor, selection, crossover, mutation, survivor, replacement, niching, operator_selection::{self, OperatorSelectors, OperatorApplication}}, configuration::{ProblemSolving, LimitConfiguration, LogLevel, Schedule, SelectionConfiguration, AgaFormulation}, helpers::{condition_checker_factory, self}, parallel::{self, WorkerPool}, schedule, statistics::GenerationStatistics, history, domain::Domain, initialization::{Initializer, InitializationContext}, cache::{self, FitnessCache}};
 use crate::configuration::GaConfiguration;
 
 #[derive(Debug, PartialEq)]
@@ -12,15 +12,77 @@ pub enum TerminationCause {
     NotTerminated
 }
 
+/**
+ * Children created in a generation
+ */
+struct Offspring<U>
+where
+    U:GenotypeT
+{
+    //The children
+    individuals: Vec<U>,
+    //Indexes of the parents of each child in the population
+    parents: Vec<(usize, usize)>,
+    //Operators applied, for the adaptive operator selection. There is one application for each couple, so for each two children
+    applications: Vec<OperatorApplication>,
+    //Fitness evaluations made and skipped for the children
+    evaluations: Evaluations,
+}
+
+impl<U> Offspring<U>
+where
+    U:GenotypeT
+{
+    /**
+     * Keeps the given number of children, with their parents and the operators applied to their couples
+     */
+    fn truncate(&mut self, children: usize) {
+        self.individuals.truncate(children);
+        self.parents.truncate(children);
+        self.applications.truncate(children.div_ceil(2));
+    }
+}
+
+/**
+ * Fitness evaluations of a step of the run. An evaluation is skipped when the fitness of the individual is still valid,
+ * and it's not counted when the fitness is taken from the fitness cache.
+ */
+#[derive(Copy, Clone, Default)]
+struct Evaluations {
+    evaluated: usize,
+    skipped: usize,
+}
+
+impl Evaluations {
+    fn add(&mut self, other: Evaluations) {
+        self.evaluated += other.evaluated;
+        self.skipped += other.skipped;
+    }
+}
+
+/**
+ * Resources created once per run and shared by its generations: the pool of workers and the fitness cache
+ */
+struct RunContext {
+    pool: WorkerPool,
+    fitness_cache: Option<Mutex<FitnessCache>>,
+}
+
 pub struct Ga<U>
 where
     U:GenotypeT
 {
     pub configuration: GaConfiguration,
     pub alleles: Vec<U::Gene>,
+    //Domain of the values of each locus, used instead of the alleles for the random initialization
+    pub domains: Vec<Domain<U::Gene>>,
     pub population: Population<U>,
     pub random_initialization: bool,
-    pub default_population: bool
+    pub default_population: bool,
+    //Individual cloned for the random initialization instead of creating a new one, for problems with instance data
+    pub prototype: Option<U>,
+    //Strategy of the random initialization, instead of the random dna from the alleles or the domains
+    pub initializer: Option<Arc<dyn Initializer<U>>>
 }
 
 
@@ -33,8 +95,11 @@ where
             configuration: GaConfiguration{..Default::default()},
             population: Population::new_empty(),
             alleles: Vec::new(),
+            domains: Vec::new(),
             random_initialization: true,
-            default_population: true
+            default_population: true,
+            prototype: None,
+            initializer: None
         }
     }
 }
@@ -51,6 +116,10 @@ where
         self.configuration.with_adaptive_ga(adaptive_ga);
         self
     }
+    fn with_aga_formulation(&mut self, aga_formulation: crate::configuration::AgaFormulation) -> &mut Self {
+        self.configuration.with_aga_formulation(aga_formulation);
+        self
+    }
     fn with_threads(&mut self, number_of_threads: i32)-> &mut Self {
         self.configuration.with_threads(number_of_threads);
         self
@@ -63,6 +132,36 @@ where
         self.configuration.with_survivor_method(method);
         self
     }
+    fn with_lambda(&mut self, lambda: i32) -> &mut Self {
+        self.configuration.with_lambda(lambda);
+        self
+    }
+    fn with_fitness_cache(&mut self, fitness_cache_size: i32) -> &mut Self {
+        self.configuration.with_fitness_cache(fitness_cache_size);
+        self
+    }
+
+    //Niching configuration
+    fn with_niching_method(&mut self, method: crate::operations::Niching) -> &mut Self {
+        self.configuration.with_niching_method(method);
+        self
+    }
+    fn with_sigma_share(&mut self, sigma_share: f64) -> &mut Self {
+        self.configuration.with_sigma_share(sigma_share);
+        self
+    }
+    fn with_sharing_alpha(&mut self, alpha: f64) -> &mut Self {
+        self.configuration.with_sharing_alpha(alpha);
+        self
+    }
+    fn with_niche_capacity(&mut self, niche_capacity: i32) -> &mut Self {
+        self.configuration.with_niche_capacity(niche_capacity);
+        self
+    }
+    fn with_window_size(&mut self, window_size: i32) -> &mut Self {
+        self.configuration.with_window_size(window_size);
+        self
+    }
 
     //Limit configuration
     fn with_problem_solving(&mut self, problem_solving: ProblemSolving)->&mut Self {
@@ -125,6 +224,10 @@ where
         self.configuration.with_crossover_method(method);
         self
     }
+    fn with_crossover_schedule(&mut self, schedule: Schedule) -> &mut Self {
+        self.configuration.with_crossover_schedule(schedule);
+        self
+    }
 
     //Mutation configuration
     fn with_mutation_probability_max(&mut self, probability_max: f64)->&mut Self {
@@ -139,6 +242,68 @@ where
         self.configuration.with_mutation_method(method);
         self
     }
+    fn with_mutation_schedule(&mut self, schedule: Schedule) -> &mut Self {
+        self.configuration.with_mutation_schedule(schedule);
+        self
+    }
+    fn with_mutation_step_size(&mut self, step_size: f64) -> &mut Self {
+        self.configuration.with_mutation_step_size(step_size);
+        self
+    }
+
+    //Operator selection configuration
+    fn with_operator_selection_method(&mut self, method: crate::operations::OperatorSelection) -> &mut Self {
+        self.configuration.with_operator_selection_method(method);
+        self
+    }
+    fn with_crossover_operators(&mut self, operators: Vec<crossover::Crossover>) -> &mut Self {
+        self.configuration.with_crossover_operators(operators);
+        self
+    }
+    fn with_mutation_operators(&mut self, operators: Vec<crate::operations::Mutation>) -> &mut Self {
+        self.configuration.with_mutation_operators(operators);
+        self
+    }
+    fn with_operator_selection_probability_min(&mut self, probability_min: f64) -> &mut Self {
+        self.configuration.with_operator_selection_probability_min(probability_min);
+        self
+    }
+    fn with_operator_selection_adaptation_rate(&mut self, adaptation_rate: f64) -> &mut Self {
+        self.configuration.with_operator_selection_adaptation_rate(adaptation_rate);
+        self
+    }
+    fn with_operator_selection_learning_rate(&mut self, learning_rate: f64) -> &mut Self {
+        self.configuration.with_operator_selection_learning_rate(learning_rate);
+        self
+    }
+    fn with_operator_selection_exploration(&mut self, exploration: f64) -> &mut Self {
+        self.configuration.with_operator_selection_exploration(exploration);
+        self
+    }
+
+    //Steady state configuration
+    fn with_steady_state(&mut self, steady_state: bool) -> &mut Self {
+        self.configuration.with_steady_state(steady_state);
+        self
+    }
+    fn with_offspring_per_step(&mut self, offspring_per_step: i32) -> &mut Self {
+        self.configuration.with_offspring_per_step(offspring_per_step);
+        self
+    }
+    fn with_replacement_method(&mut self, method: crate::operations::Replacement) -> &mut Self {
+        self.configuration.with_replacement_method(method);
+        self
+    }
+
+    //Statistics configuration
+    fn with_diversity_statistics(&mut self, diversity: bool) -> &mut Self {
+        self.configuration.with_diversity_statistics(diversity);
+        self
+    }
+    fn with_distance_metric(&mut self, distance: crate::configuration::Distance) -> &mut Self {
+        self.configuration.with_distance_metric(distance);
+        self
+    }
 
     //Save progress configuration
     fn with_save_progress(&mut self, save_progress: bool) -> &mut Self {
@@ -153,6 +318,24 @@ where
         self.configuration.with_save_progress_path(save_progress_path);
         self
     }
+
+    //History configuration
+    fn with_export_history(&mut self, export_history: bool) -> &mut Self {
+        self.configuration.with_export_history(export_history);
+        self
+    }
+    fn with_history_path(&mut self, history_path: String) -> &mut Self {
+        self.configuration.with_history_path(history_path);
+        self
+    }
+    fn with_history_format(&mut self, history_format: crate::configuration::HistoryFormat) -> &mut Self {
+        self.configuration.with_history_format(history_format);
+        self
+    }
+    fn with_best_individual_path(&mut self, best_individual_path: String) -> &mut Self {
+        self.configuration.with_best_individual_path(best_individual_path);
+        self
+    }
 }
 
 
@@ -168,6 +351,31 @@ where
         self
     }
 
+    /**
+     * Function to set the domains of the loci: one domain for every locus or one per locus.
+     * They are used by the random initialization instead of the alleles, and by the mutations.
+     */
+    pub fn with_domains(&mut self, domains: Vec<Domain<U::Gene>>) -> &mut Self {
+        self.domains = domains;
+        self
+    }
+
+    /**
+     * Function to set the strategy of the random initialization
+     */
+    pub fn with_initializer<I: Initializer<U> + 'static>(&mut self, initializer: I) -> &mut Self {
+        self.initializer = Some(Arc::new(initializer));
+        self
+    }
+
+    /**
+     * Function to set the individual cloned for the random initialization, instead of a new one
+     */
+    pub fn with_prototype(&mut self, prototype: U) -> &mut Self {
+        self.prototype = Some(prototype);
+        self
+    }
+
     /**
      * Function to set the population
      */
@@ -190,70 +398,74 @@ where
     where U:GenotypeT + Send + Sync + 'static + Clone
     {
         //Before starting the run, we will check the conditions
-        condition_checker_factory::<U>(Some(&self.configuration), None, Some(&self.alleles), self.default_population);
-
-        info!("Random initialization started");
-        //let mut individuals = Vec::new();
-        let (tx, rx) = sync_channel(self.configuration.number_of_threads as usize);
-
-        //Setting the number of individuals per thread
-        let individuals_per_thread = self.configuration.limit_configuration.population_size / self.configuration.number_of_threads;
-
-        //Cloning the individuals for multithreading
-        let alleles_t = Arc::new(Mutex::new(self.alleles.clone()));
-
-        //Walking through the threads
-        for _ in 0..self.configuration.number_of_threads {
-
-        //Cloning the information from the main thread
-        let (tx, 
-        alleles_t, 
-        alleles_can_be_repeated_t, 
-        genes_per_individual_t, 
-        individuals_per_thread_t,
-        needs_unique_ids_t) = (tx.clone(), Arc::clone(&alleles_t), self.configuration.limit_configuration.alleles_can_be_repeated, 
-                                    self.configuration.limit_configuration.genes_per_individual, individuals_per_thread, 
-                                    self.configuration.limit_configuration.needs_unique_ids);
-
-        //Starting the thread management
-        thread::spawn(move || {
-
-        let mut individuals = Vec::new();
+        condition_checker_factory::<U>(Some(&self.configuration), None, Some(&self.alleles), &self.domains, self.default_population);
 
-        for _ in 0..individuals_per_thread_t{
+        let pool = WorkerPool::new(self.configuration.number_of_threads as usize);
+        self.initialize_population(&pool)
+    }
 
-        let mut individual = U::new();
+    /**
+     * Initializes the population with the initializer or randomly, in the workers of the given pool
+     */
+    fn initialize_population(&self, pool: &WorkerPool)->Population<U>
+    where U:GenotypeT + Send + Sync + 'static + Clone
+    {
+        info!("Random initialization started");
 
-        //Gets the dna randomly
-        if alleles_can_be_repeated_t {
-        let dna_individual = helpers::initialize_dna::<U>(&alleles_t.lock().unwrap(), genes_per_individual_t, needs_unique_ids_t);
-        individual.set_dna(dna_individual.as_slice());
-        }else{
-        let dna_individual = helpers::initialize_dna_without_repeated_alleles::<U>(&alleles_t.lock().unwrap(), genes_per_individual_t, needs_unique_ids_t);
-        individual.set_dna(dna_individual.as_slice());
+        //With an initializer, the population is created by its strategy
+        if let Some(initializer) = &self.initializer {
+            let limit_configuration = &self.configuration.limit_configuration;
+            let context = InitializationContext {
+                population_size: limit_configuration.population_size as usize,
+                genes_per_individual: limit_configuration.genes_per_individual as usize,
+                alleles: &self.alleles,
+                domains: &self.domains,
+                alleles_can_be_repeated: limit_configuration.alleles_can_be_repeated,
+                needs_unique_ids: limit_configuration.needs_unique_ids,
+                limit_configuration,
+                prototype: self.prototype.as_ref(),
+            };
+            let mut individuals = initializer.initialize(&context);
+            //The fitness is only valid if the initializer calculated it
+            if initializer.evaluates_fitness() {
+                individuals.iter_mut().for_each(|individual| {individual.set_fitness_valid(true);});
+            }
+            return Population::new(individuals);
         }
 
-        //Sets the dna of the individual, the age, and calculates fitness
-        individual.set_age(0);
-        individual.calculate_fitness();
+        //Setting the number of individuals per thread, distributing the remainder
+        let individuals_per_thread = parallel::chunk_sizes(self.configuration.limit_configuration.population_size as usize, pool.threads());
+        let limit_configuration = &self.configuration.limit_configuration;
+        let (alleles, domains, prototype) = (&self.alleles, &self.domains, &self.prototype);
 
-        //Adds the individual in the vector
-        individuals.push(individual);
+        //Each worker initializes its individuals from the borrowed alleles or domains
+        let individuals = pool.map(individuals_per_thread, |individuals_per_thread| {
+            let mut individuals = Vec::with_capacity(individuals_per_thread);
 
-        }
+            for _ in 0..individuals_per_thread{
 
-        //we send the individuals randomly initialized
-        tx.send(individuals).unwrap();
-        });
-        }
+                let mut individual = prototype.clone().unwrap_or_else(U::new);
 
-        drop(tx);
+                //Gets the dna randomly, from the domains of the loci if they are set
+                let dna_individual = if !domains.is_empty() {
+                    helpers::initialize_dna_from_domains::<U>(domains, limit_configuration.genes_per_individual, limit_configuration.needs_unique_ids)
+                }else if limit_configuration.alleles_can_be_repeated {
+                    helpers::initialize_dna::<U>(alleles, limit_configuration.genes_per_individual, limit_configuration.needs_unique_ids)
+                }else{
+                    helpers::initialize_dna_without_repeated_alleles::<U>(alleles, limit_configuration.genes_per_individual, limit_configuration.needs_unique_ids)
+                };
 
-        // We receive from the threads and add them into individuals
-        let mut individuals = Vec::new();
-        for mut received in rx {
-        individuals.append(&mut received);
-        }
+                //Sets the dna of the individual, the age, and calculates fitness
+                individual.set_dna(dna_individual.as_slice());
+                individual.set_age(0);
+                individual.calculate_fitness();
+                individual.set_fitness_valid(true);
+
+                //Adds the individual in the vector
+                individuals.push(individual);
+            }
+            individuals
+        }).into_iter().flatten().collect();
 
         Population::new(individuals)
 
@@ -272,11 +484,21 @@ where
         F: Fn(&i32, &Population<U>, TerminationCause)
     {
         //Before starting the run, we will check the conditions
-        condition_checker_factory::<U>(Some(&self.configuration), Some(&self.population), Some(&self.alleles), self.default_population);
+        condition_checker_factory::<U>(Some(&self.configuration), Some(&self.population), Some(&self.alleles), &self.domains, self.default_population);
 
-        //If we want to initialize the population randomly
+        //The workers are created once and used in every generation of the run
+        let context = RunContext {
+            pool: WorkerPool::new(self.configuration.number_of_threads as usize),
+            fitness_cache: self.configuration.fitness_cache_size.map(|fitness_cache_size| Mutex::new(FitnessCache::new(fitness_cache_size as usize))),
+        };
+
+        //If we want to initialize the population randomly. Its fitness is calculated in the initialization, unless the initializer doesn't calculate it
+        let initialized = self.random_initialization && match &self.initializer {
+            Some(initializer) => initializer.evaluates_fitness(),
+            None => true,
+        };
         if self.random_initialization {
-            let tmp_population=self.random_initialization();
+            let tmp_population=self.initialize_population(&context.pool);
             self.with_population(tmp_population);
         }   
 
@@ -293,18 +515,36 @@ where
         env::set_var(key, log_level.as_str());
         let _ = env_logger::try_init();
 
-        //Initialize the adaptive ga
-        if self.configuration.adaptive_ga{
-            self.population.aga_init();
-        }
-
         //Best individual within the generations and population returned
         let initial_population_size = self.population.size();
         let mut age = 0;
 
         //Calculation of the fitness and the best individual
-        let mut best_individual = population_fitness_calculation(&mut self.population.individuals, self.configuration.clone());
+        let start_time = Instant::now();
+        self.population.statistics.clear();
+        let (mut best_individual, mut evaluations) = population_fitness_calculation(&mut self.population.individuals, self.configuration.clone(), &context);
+        //The valid fitness values of an initialized population were calculated by the initialization, so they are counted as evaluations
+        if initialized {
+            evaluations.evaluated += evaluations.skipped;
+            evaluations.skipped = 0;
+        }
+
+        //Initialize the adaptive ga, once the fitness of the population is calculated
+        if self.configuration.adaptive_ga{
+            self.population.aga_statistics(&self.configuration.limit_configuration);
+        }
         let mut best_population: Population<U> = Population::new_empty();
+        let aga = if self.configuration.adaptive_ga {Some(self.configuration.limit_configuration)} else {None};
+
+        //Adaptive operator selection over the crossover and mutation portfolios
+        let operator_selection_configuration = self.configuration.operator_selection_configuration.clone();
+        let mut selectors = OperatorSelectors::new(&operator_selection_configuration);
+
+        //In steady state, each generation is a step producing only a few children
+        let steady_state_configuration = self.configuration.steady_state_configuration;
+
+        //Number of children per generation, only taken into account if it is set
+        let lambda = self.configuration.lambda.unwrap_or(0) as usize;
 
         // Starting counting the generations for the callback
         let mut generation_callback_count = 0;
@@ -316,31 +556,133 @@ where
             info!(target="ga_events", method="run"; "Generation number: {}", i+1);
             age += 1;
 
-            //1- Parent selection for reproduction
-            let mut parents = selection::factory(&self.population.individuals, self.configuration.selection_configuration, self.configuration.number_of_threads);
-            debug!(target="ga_events", method="run"; "Parents selected for reproduction");
+            //1- Parameter control of the crossover and mutation probabilities for this generation
+            let mut generation_configuration = self.configuration.clone();
+            let (crossover_configuration, mutation_configuration) = (self.configuration.crossover_configuration, self.configuration.mutation_configuration);
+            generation_configuration.crossover_configuration.probability_max = schedule::probability(crossover_configuration.schedule, i, self.configuration.limit_configuration.max_generations, 
+                                                                                                       crossover_configuration.probability_max, crossover_configuration.probability_min);
+            generation_configuration.mutation_configuration.probability_max = schedule::probability(mutation_configuration.schedule, i, self.configuration.limit_configuration.max_generations, 
+                                                                                                      mutation_configuration.probability_max, mutation_configuration.probability_min);
+            self.population.crossover_probability = generation_configuration.crossover_configuration.probability_max.unwrap_or(1.0);
+            self.population.mutation_probability = generation_configuration.mutation_configuration.probability_max.unwrap_or(1.0);
+
+            //1.2- With fitness sharing or clearing, the parents are selected by their niche fitness
+            let shared_individuals = self.configuration.niching_configuration.method.map(|niching| {
+                let mut individuals = self.population.individuals.clone();
+                niching::factory(niching, &mut individuals, &self.configuration.niching_configuration, &self.configuration.limit_configuration);
+                individuals
+            });
+            let selection_individuals = shared_individuals.as_ref().unwrap_or(&self.population.individuals);
+
+            //2- Getting the offspring, breeding until lambda children are created if it is set
+            let mut offspring = Offspring { individuals: Vec::new(), parents: Vec::new(), applications: Vec::new(), evaluations: Evaluations::default() };
+            loop {
+
+                //2.1- Parent selection for reproduction
+                let mut parents = if steady_state_configuration.steady_state {
+                    //In steady state, only the couples needed for the offspring of the step are selected
+                    let number_of_couples = (steady_state_configuration.offspring_per_step + 1) / 2;
+                    let selection_configuration = SelectionConfiguration { number_of_couples, ..self.configuration.selection_configuration };
+                    //The couples are shuffled from a sorted order, so the seeded runs are reproducible
+                    let mut couples: Vec<(usize, usize)> = selection::factory_with_pool(selection_individuals, selection_configuration, &context.pool).into_iter().collect();
+                    couples.sort_unstable();
+                    couples.shuffle(&mut crate::rng::rng());
+                    couples.into_iter().take(number_of_couples as usize).collect()
+                } else {
+                    selection::factory_with_pool(selection_individuals, self.configuration.selection_configuration, &context.pool)
+                };
+                debug!(target="ga_events", method="run"; "Parents selected for reproduction");
+                if parents.is_empty() {
+                    break;
+                }
 
-            //2- Getting the offspring
-            let mut offspring = parent_crossover(&mut parents, &self.population.individuals, &self.configuration, age, self.population.f_max, self.population.f_avg);
+                //2.2- Crossover and mutation of the parents
+                let mut children = parent_crossover(&mut parents, &self.population, &generation_configuration, &self.domains, age, &selectors, &context);
+                evaluations.add(children.evaluations);
+                offspring.individuals.append(&mut children.individuals);
+                offspring.parents.append(&mut children.parents);
+                offspring.applications.append(&mut children.applications);
+
+                if steady_state_configuration.steady_state || offspring.individuals.len() >= lambda {
+                    break;
+                }
+            }
+            if steady_state_configuration.steady_state {
+                offspring.truncate(steady_state_configuration.offspring_per_step as usize);
+            } else if self.configuration.lambda.is_some() {
+                offspring.truncate(lambda);
+            }
             debug!(target="ga_events", method="run"; "Offspring created");
 
+            //2.3- Credit assignment of the operators applied for the adaptive operator selection
+            if let Some(selector) = selectors.crossover.as_mut() {
+                let rewards: Vec<(usize, f64)> = offspring.applications.iter().filter_map(|application| application.crossover.map(|operator| (operator, application.reward))).collect();
+                selector.update(&rewards);
+                self.population.crossover_usage = selector.usage(&operator_selection_configuration.crossover_operators, &rewards);
+            }
+            if let Some(selector) = selectors.mutation.as_mut() {
+                let rewards: Vec<(usize, f64)> = offspring.applications.iter().filter_map(|application| application.mutation.map(|operator| (operator, application.reward))).collect();
+                selector.update(&rewards);
+                self.population.mutation_usage = selector.usage(&operator_selection_configuration.mutation_operators, &rewards);
+            }
+
             //3- Sets the best individual
-            for child in &offspring{
-                best_individual = get_best_individual(&best_individual, child, self.configuration.limit_configuration.problem_solving);
+            for child in &offspring.individuals{
+                best_individual = get_best_individual(&best_individual, child, &self.configuration.limit_configuration);
             }
             debug!(target="ga_events", method="run"; "Best individual calculated - generation {}", i+1);
 
             //3.1- If we want to return the best individual by generation
             if self.configuration.limit_configuration.get_best_individual_by_generation {
-                best_population.add_individual_gn(best_individual.clone(), i, self.configuration.adaptive_ga);
+                best_population.add_scored_individual_gn(best_individual.clone(), i, aga.as_ref());
             }
 
-            //4- Insert the children in the population
-            self.population.add_individuals(&mut offspring, self.configuration.adaptive_ga);
+            if steady_state_configuration.steady_state {
+
+                //4- In steady state, each child is inserted in the population by the replacement policy
+                for (child, parents) in offspring.individuals.into_iter().zip(offspring.parents){
+                    replacement::factory(steady_state_configuration.replacement, &mut self.population.individuals, child, parents, self.configuration.limit_configuration);
+                }
+                debug!(target="ga_events", method="run"; "Offspring inserted");
 
-            //5- Survivor selection
-            survivor::factory(self.configuration.survivor, &mut self.population.individuals, initial_population_size, self.configuration.limit_configuration);
-            debug!(target="ga_events", method="run"; "Survivors selected");
+            } else if survivor::is_niching(self.configuration.survivor) {
+
+                //4- With the niching survivors, each child competes with a similar individual of the population
+                survivor::niching_factory(self.configuration.survivor, &mut self.population.individuals, offspring.individuals, &offspring.parents, &self.configuration);
+                debug!(target="ga_events", method="run"; "Survivors selected");
+
+            } else {
+
+                //4- Insert the children in the population, discarding the parents in a (mu, lambda) strategy
+                if self.configuration.survivor == Survivor::MuCommaLambda {
+                    self.population.individuals.clear();
+                }
+                self.population.add_individuals(&mut offspring.individuals, false);
+
+                //5- Survivor selection
+                survivor::factory(self.configuration.survivor, &mut self.population.individuals, initial_population_size, self.configuration.limit_configuration);
+                debug!(target="ga_events", method="run"; "Survivors selected");
+            }
+
+            //5.1- The statistics of the adaptive ga are calculated over the survivors
+            if self.configuration.adaptive_ga {
+                self.population.aga_statistics(&self.configuration.limit_configuration);
+            }
+
+            //5.2- Statistics of the generation
+            let diversity = if self.configuration.statistics_configuration.diversity {
+                Some(self.population.diversity(self.configuration.statistics_configuration.distance))
+            } else {
+                None
+            };
+            self.population.statistics.push(GenerationStatistics {
+                elapsed_seconds: start_time.elapsed().as_secs_f64(),
+                crossover_probability: self.population.crossover_probability,
+                mutation_probability: self.population.mutation_probability,
+                skipped_evaluations: evaluations.skipped,
+                cache: context.fitness_cache.as_ref().map(|fitness_cache| fitness_cache.lock().unwrap().statistics()),
+                ..GenerationStatistics::new(i, &self.population.individuals, best_individual.get_fitness(), evaluations.evaluated, diversity)
+            });
 
             // If we want to perform a callback
             if let Some(func) = &callback {
@@ -366,7 +708,7 @@ where
 
         //If it's not required to return the best individuals by generation
         if !self.configuration.limit_configuration.get_best_individual_by_generation {
-            best_population.add_individual_gn(best_individual, -1, self.configuration.adaptive_ga);
+            best_population.add_scored_individual_gn(best_individual.clone(), -1, aga.as_ref());
         }
 
         // If we want to perform a callback and the fitness target is not reached
@@ -377,6 +719,15 @@ where
             }
         }
 
+        //The statistics of the run are kept in the result
+        best_population.statistics = self.population.statistics.clone();
+
+        //Export of the history of the run. A failed export doesn't discard the result of the run
+        if self.configuration.history_configuration.export_history {
+            if let Err(export_error) = history::export(&best_population.statistics, &best_individual, &self.configuration.history_configuration) {
+                error!(target="ga_events", method="run"; "The history could not be exported: {}", export_error);
+            }
+        }
         best_population
     }
 }
@@ -384,38 +735,18 @@ where
 /**
  * Function to determine which of the individuals is the best individual and return the best of them
  */
-fn get_best_individual<U>(individual_1: &U, individual_2: &U, problem_solving: ProblemSolving) -> U
+fn get_best_individual<U>(individual_1: &U, individual_2: &U, limit_configuration: &LimitConfiguration) -> U
 where
 U:GenotypeT
 {
 
     debug!(target="ga_events", method="get_best_individual"; "Started the best individual method");
-    let mut best_individual = U::new();
     trace!(target="ga_events", method="get_best_individual"; "Individual 1 fitness: {} - Individual 2 fitness: {}", individual_1.get_fitness(), individual_2.get_fitness());
 
-    if problem_solving == ProblemSolving::Maximization {
-
-        //We check if the fitness is the best and store it if it's the case
-        if individual_1.get_fitness() >= individual_2.get_fitness(){
-            best_individual.set_dna(individual_1.get_dna());
-            best_individual.set_fitness(individual_1.get_fitness());
-        }else{
-            best_individual.set_dna(individual_2.get_dna());
-            best_individual.set_fitness(individual_2.get_fitness());
-        }
-
-    } else {
-
-        //We check if the fitness is the best and store it if it's the case
-        if individual_1.get_fitness() >= individual_2.get_fitness(){
-            best_individual.set_dna(individual_2.get_dna());
-            best_individual.set_fitness(individual_2.get_fitness());
-        }else{
-            best_individual.set_dna(individual_1.get_dna());
-            best_individual.set_fitness(individual_1.get_fitness());
-        }
-
-    }
+    //We check if the fitness is the best and store it if it's the case. With the same fitness, the first individual is kept.
+    //The individual is cloned to keep the data of the genotype beyond the dna, such as the instance of the problem
+    let best = if helpers::fitness_ordering(individual_2, individual_1, limit_configuration) == Ordering::Less {individual_2} else {individual_1};
+    let best_individual = best.clone();
 
     debug!(target="ga_events", method="get_best_individual"; "Best individual method finished");
     best_individual
@@ -458,233 +789,210 @@ U:GenotypeT
 }
 
 /**
- * Sets the population fitness, age and the best individual
+ * Sets the population fitness, age and the best individual. With the fitness cache, the fitness is taken from it when it's there.
+ * The individuals with a valid fitness are not evaluated again, and their fitness is stored in the cache. Each individual is counted once,
+ * as evaluated or as skipped.
  */
-fn population_fitness_calculation<U>(individuals: &mut Vec<U>, configuration: GaConfiguration) -> U
+fn population_fitness_calculation<U>(individuals: &mut [U], configuration: GaConfiguration, context: &RunContext) -> (U, Evaluations)
 where
 U:GenotypeT + Send + Sync + 'static + Clone
 {
 
     debug!(target="ga_events", method="population_fitness_calculation"; "Started the population fitness calculation");
-    let mut number_of_threads = configuration.number_of_threads;
-    let (tx, rx) = sync_channel(number_of_threads as usize);
-
-    //Division of the individuals in different threads
-    number_of_threads = if number_of_threads > individuals.len() as i32 {individuals.len() as i32} else {number_of_threads};
-
-    //Setting the starting point and the jump
-    let mut start_index = 0;
-    let mut jump = individuals.len() as i32 / number_of_threads;
-
-    //Cloning the individuals for multithreading
-    let individuals_t = Vec::from_iter(individuals[..].iter().cloned());
-    let individuals_t = Arc::new(Mutex::new(individuals_t));
-
-    let best_individual_t = Arc::new(Mutex::new(U::new()));
-
-    //Walking through the threads
-    for _ in 0..number_of_threads {
-
-        //We calculate the next jump
-        if jump > individuals.len() as i32 - (start_index + jump) {
-            jump += individuals.len() as i32 - (start_index + jump);
-        }
-
-        //Cloning the information from the main thread
-        let (start_index_t, tx, jump_t, individuals_t, best_individual_t) = (start_index, tx.clone(),  jump, Arc::clone(&individuals_t), Arc::clone(&best_individual_t));
-
-        //Starting the thread management
-        thread::spawn(move || {
-
-            let mut fitness_map = HashMap::new();
-            let mut best_individual = U::new();
-
-            //Calculates the fitness from the corresponding population
-            for i in start_index_t..(start_index_t + jump_t){
-                individuals_t.lock().unwrap()[i as usize].calculate_fitness();
-                fitness_map.insert(i as usize, individuals_t.lock().unwrap()[i as usize].get_fitness());
-
-                if !best_individual.get_dna().is_empty() {
-                    best_individual = get_best_individual(&best_individual, &individuals_t.lock().unwrap()[i as usize], configuration.limit_configuration.problem_solving);
-                } else{
-                    best_individual.set_dna(individuals_t.lock().unwrap()[i as usize].get_dna());
-                    best_individual.set_fitness(individuals_t.lock().unwrap()[i as usize].get_fitness());
-                }
-            }
-
-            //Setting the best global individual
-            if !best_individual_t.lock().unwrap().get_dna().is_empty() {
-                let global_best_individual = get_best_individual(&best_individual_t.lock().unwrap().clone(), &best_individual, configuration.limit_configuration.problem_solving);
-                best_individual_t.lock().unwrap().set_dna(global_best_individual.get_dna());
-                best_individual_t.lock().unwrap().set_fitness(global_best_individual.get_fitness());
-            }else{
-                best_individual_t.lock().unwrap().set_dna(best_individual.get_dna());
-                best_individual_t.lock().unwrap().set_fitness(best_individual.get_fitness());
+    let fitness_cache = context.fitness_cache.as_ref();
+    let evaluations_per_thread = context.pool.map_owned_chunks(individuals, |chunk| {
+        let mut evaluations = Evaluations::default();
+        for individual in chunk.iter_mut() {
+            if individual.is_fitness_valid() {
+                cache::store_fitness(individual, fitness_cache);
+                evaluations.skipped += 1;
+            } else if cache::calculate_fitness(individual, fitness_cache) {
+                evaluations.evaluated += 1;
             }
-
-            //Sending the result
-            tx.send(fitness_map).unwrap();
-        });
-
-        start_index += jump;
-    }
-
-    drop(tx);
-
-    //We receive from the threads and set the fitness in individuals
-    for received in rx {
-        for element in received{
-            individuals[element.0].set_fitness(element.1);
         }
-    }
+        evaluations
+    });
+    let mut evaluations = Evaluations::default();
+    evaluations_per_thread.into_iter().for_each(|evaluations_t| evaluations.add(evaluations_t));
 
-    let mut best_individual = U::new();
-    best_individual.set_dna(best_individual_t.lock().unwrap().get_dna());
-    best_individual.set_fitness(best_individual_t.lock().unwrap().get_fitness());
+    //Setting the best individual. With the same fitness, the first individual is kept
+    let best_individual = individuals.iter()
+        .reduce(|best_individual, individual| if helpers::fitness_ordering(individual, best_individual, &configuration.limit_configuration) == Ordering::Less {individual} else {best_individual})
+        .cloned()
+        .unwrap_or_else(U::new);
 
     debug!(target="ga_events", method="population_fitness_calculation"; "Population fitness calculation finished");
 
-    best_individual
+    (best_individual, evaluations)
 }
 
 /**
  * Function for parent crossover
  */
-fn parent_crossover<U>(parents: &mut HashMap<usize, usize>, individuals: &Vec<U>, configuration: &GaConfiguration, age: i32, f_max: f64, f_avg: f64) -> Vec<U>
+fn parent_crossover<U>(parents: &mut HashMap<usize, usize>, population: &Population<U>, configuration: &GaConfiguration, domains: &[Domain<U::Gene>], age: i32,
+                       selectors: &OperatorSelectors, context: &RunContext) -> Offspring<U>
 where 
 U:GenotypeT + Send + Sync + 'static + Clone
 {
     //Setting the control variables
     debug!(target="ga_events", method="parent_crossover"; "Started the parent crossover");
-    let number_of_threads = if configuration.number_of_threads > parents.len() as i32 {parents.len() as i32}else{configuration.number_of_threads};
-    let jump = parents.len() / number_of_threads as usize;
+    let (pool, fitness_cache) = (&context.pool, context.fitness_cache.as_ref());
+    let couples_per_thread = parallel::chunk_sizes(parents.len(), pool.threads());
+
+    //The couples are walked in order, so the seeded runs are reproducible
+    let mut keys: Vec<usize> = parents.keys().copied().collect();
+    keys.sort_unstable();
+    let mut keys = keys.into_iter();
 
-    let mut handles = Vec::new();
-    let offspring = Arc::new(Mutex::new(Vec::new()));
+    let (f_max, f_avg) = (population.f_max, population.f_avg);
 
     /*
         Gets the static crossover probability config and the static mutation probability config
         This way we avoid of passing by these conditions at each thread if it's not necessary
     */
     let crossover_probability_config = 
-            if configuration.crossover_configuration.probability_max.is_none(){
-                Some(1.0)
-            }else if !configuration.adaptive_ga{
-                Some(configuration.crossover_configuration.probability_max.unwrap())
-            }else{
-                    None
+            match configuration.crossover_configuration.probability_max {
+                None => Some(1.0),
+                Some(probability_max) if !configuration.adaptive_ga => Some(probability_max),
+                _ => None,
             };
 
     let mutation_probability_config =
-            if configuration.mutation_configuration.probability_max.is_none(){
-                Some(1.0)
-            }else if !configuration.adaptive_ga{
-                Some(configuration.mutation_configuration.probability_max.unwrap())
-            }else{
-                None
+            match configuration.mutation_configuration.probability_max {
+                None => Some(1.0),
+                Some(probability_max) if !configuration.adaptive_ga => Some(probability_max),
+                _ => None,
             };
 
-    //Run all the threads
-    for t in 0..number_of_threads{
+    //The couples of each thread
+    let parents_per_thread: Vec<Vec<(usize, usize)>> = couples_per_thread.into_iter()
+        .map(|couples| keys.by_ref().take(couples).map(|key| (key, parents.remove(&key).unwrap())).collect()).collect();
+
+    //Each worker crosses its couples, borrowing the population, and returns its offspring
+    let offsprings = pool.map(parents_per_thread, |parents_t| {
+
+        //Getting random numbers in this thread
+        let mut rng = crate::rng::rng();
+        let (mut crossover_selector, mut mutation_selector) = (selectors.crossover.clone(), selectors.mutation.clone());
+        let mut offspring = Offspring { individuals: Vec::new(), parents: Vec::new(), applications: Vec::new(), evaluations: Evaluations::default() };
+
+        for(key, value) in parents_t.iter(){
+            //Getting the parent 1 and 2 for crossover                
+            let parent_1 = population.individuals.get(*key).unwrap();
+            let parent_2 = population.individuals.get(*value).unwrap();
+
+            //Making the crossover of the parents when the random number is below or equal to the given probability
+            let crossover_probability = rng.gen_range(0.0..1.0);
+            let crossover_probability_config = match crossover_probability_config {
+                Some(probability) => probability,
+                None => {
+                    let (probability_max, probability_min) = (configuration.crossover_configuration.probability_max.unwrap(), configuration.crossover_configuration.probability_min.unwrap());
+                    match configuration.aga_formulation {
+                        AgaFormulation::Simplified => crossover::aga_probability_with_limit_configuration(parent_1, parent_2, f_max, f_avg, probability_max, probability_min, &configuration.limit_configuration),
+                        AgaFormulation::SrinivasPatnaik => crossover::srinivas_patnaik_probability(parent_1, parent_2, f_max, f_avg, probability_max, probability_min, &configuration.limit_configuration),
+                    }
+                },
+            };
 
-        //We copy the parents that we want to crossover inside the thread
-        let (individuals, configuration, offspring, crossover_probability_config, mutation_probability_config) = (individuals.clone(), configuration.clone(), Arc::clone(&offspring), crossover_probability_config, mutation_probability_config);
-        let mut parents_t = HashMap::new();
-        let parents_c = parents.clone();
+            //Making the mutation of each child when the random number is below or equal the given probability
+            let mut mutation_probability = rng.gen_range(0.0..1.0);
+            let mutation_probability_config = match mutation_probability_config {
+                Some(probability) => probability,
+                None => {
+                    let (probability_max, probability_min) = (configuration.mutation_configuration.probability_max.unwrap(), configuration.mutation_configuration.probability_min.unwrap());
+                    match configuration.aga_formulation {
+                        AgaFormulation::Simplified => mutation::aga_probability_with_limit_configuration(parent_1, parent_2, f_avg, probability_max, probability_min, &configuration.limit_configuration),
+                        AgaFormulation::SrinivasPatnaik => mutation::srinivas_patnaik_probability(parent_1, parent_2, f_max, f_avg, probability_max, probability_min, &configuration.limit_configuration),
+                    }
+                },
+            };
 
-        for (index, i) in parents_c.keys().enumerate(){
+            //With adaptive operator selection, the operators are taken from the portfolio
+            let crossover_operator = crossover_selector.as_mut().map(|selector| selector.select());
+            let mutation_operator = mutation_selector.as_mut().map(|selector| selector.select());
 
-            //If we reach the number of crossovers / thread
-            if t < number_of_threads - 1 && index >= jump {
-                break;
+            let mut crossover_configuration = configuration.crossover_configuration;
+            if let Some(operator) = crossover_operator {
+                crossover_configuration.method = configuration.operator_selection_configuration.crossover_operators[operator];
+            }
+            let mut mutation_configuration = configuration.mutation_configuration;
+            if let Some(operator) = mutation_operator {
+                mutation_configuration.method = configuration.operator_selection_configuration.mutation_operators[operator];
             }
 
-            let key = *parents.get_key_value(i).unwrap().0;
-            parents_t.insert(key, *parents.get_key_value(i).unwrap().1);
-            parents.remove(&key);
-        }
+            debug!(target="ga_events", method="parent_crossover"; "Started the parent crossover");
 
-        //Starts the thread
-        let handle = thread::spawn(move || {
-
-            //Getting random numbers in this thread
-            let mut rng = rand::thread_rng();
-
-            for(key, value) in parents_t.iter(){
-                //Getting the parent 1 and 2 for crossover                
-                let parent_1 = individuals.get(*key).unwrap().clone();
-                let parent_2 = individuals.get(*value).unwrap().clone();
-
-                //Making the crossover of the parents when the random number is below or equal to the given probability
-                let crossover_probability = rng.gen_range(0.0..1.0);
-                let crossover_probability_config = 
-                    if crossover_probability_config.is_some(){
-                        crossover_probability_config.unwrap()
-                    }else{
-                        crossover::aga_probability(&parent_1, &parent_2, f_max, f_avg, configuration.crossover_configuration.probability_max.unwrap(), configuration.crossover_configuration.probability_min.unwrap())
-                    };
-                
-
-                //Making the mutation of each child when the random number is below or equal the given probability
-                let mut mutation_probability = rng.gen_range(0.0..1.0);
-                let mutation_probability_config = 
-                    if mutation_probability_config.is_some(){
-                        mutation_probability_config.unwrap()
-                    }else{
-                        mutation::aga_probability(&parent_1, &parent_2, f_avg, configuration.mutation_configuration.probability_max.unwrap(), configuration.mutation_configuration.probability_min.unwrap())
-                    };
-
-                debug!(target="ga_events", method="parent_crossover"; "Started the parent crossover");
-
-                let mut child_1: U;
-                let mut child_2: U;
-                let mut offspring_t: Vec<U> = vec![];
-
-                if crossover_probability <= crossover_probability_config {
-                    offspring_t = crossover::factory(&parent_1, &parent_2, configuration.crossover_configuration).unwrap();
-                    child_1 = offspring_t.pop().unwrap();
-                    child_2 = offspring_t.pop().unwrap();
-                }else{
-                    child_1 = parent_1;
-                    child_2 = parent_2;
-                }
-                
-                if configuration.mutation_configuration.probability_max.is_none(){1.0}else{configuration.mutation_configuration.probability_max.unwrap()};
-                debug!(target="ga_events", method="parent_crossover"; "mutation_probability_config {} - mutation probability {}", mutation_probability_config, mutation_probability);
+            let mut child_1: U;
+            let mut child_2: U;
+            let mut offspring_t: Vec<U> = vec![];
+            let crossed = crossover_probability <= crossover_probability_config;
+            let (mut mutated_1, mut mutated_2) = (false, false);
 
-                if mutation_probability < mutation_probability_config {
-                    mutation::factory(configuration.mutation_configuration.method, &mut child_1);
-                }
+            if crossed {
+                offspring_t = crossover::factory(parent_1, parent_2, crossover_configuration).unwrap();
+                child_1 = offspring_t.pop().unwrap();
+                child_2 = offspring_t.pop().unwrap();
+            }else{
+                child_1 = parent_1.clone();
+                child_2 = parent_2.clone();
+            }
+            
+            debug!(target="ga_events", method="parent_crossover"; "mutation_probability_config {} - mutation probability {}", mutation_probability_config, mutation_probability);
 
-                mutation_probability = rng.gen_range(0.0..1.0);
-                if mutation_probability <= mutation_probability_config {
-                    mutation::factory(configuration.mutation_configuration.method, &mut child_2);
-                }
+            if mutation_probability < mutation_probability_config {
+                mutation::factory_with_domains(&mut child_1, mutation_configuration, domains);
+                mutated_1 = true;
+            }
 
-                //Calculate the fitness of both children and set their age
-                child_1.calculate_fitness();
-                child_2.calculate_fitness();
+            mutation_probability = rng.gen_range(0.0..1.0);
+            if mutation_probability <= mutation_probability_config {
+                mutation::factory_with_domains(&mut child_2, mutation_configuration, domains);
+                mutated_2 = true;
+            }
 
-                child_1.set_age(age);
-                child_2.set_age(age);
+            //Calculate the fitness of the children that changed, from the fitness cache if it's enabled, and set their age.
+            //The children that were neither crossed nor mutated keep the fitness of their parents
+            for (child, changed) in [(&mut child_1, crossed || mutated_1), (&mut child_2, crossed || mutated_2)] {
+                if !changed {
+                    offspring.evaluations.skipped += 1;
+                } else if cache::calculate_fitness(child, fitness_cache) {
+                    offspring.evaluations.evaluated += 1;
+                }
+            }
 
-                //Adds the children in the offspring
-                offspring_t.push(child_1);
-                offspring_t.push(child_2);
-                
-                //Then sets the offspring in the result vector
-                offspring.lock().unwrap().append(&mut offspring_t);
+            child_1.set_age(age);
+            child_2.set_age(age);
+
+            //Records the improvement obtained by the operators of the portfolio
+            if crossover_operator.is_some() || mutation_operator.is_some() {
+                let reward = operator_selection::improvement(parent_1, parent_2, &child_1, &configuration.limit_configuration)
+                                .max(operator_selection::improvement(parent_1, parent_2, &child_2, &configuration.limit_configuration));
+                offspring.applications.push(OperatorApplication {
+                    crossover: crossover_operator.filter(|_| crossed),
+                    mutation: mutation_operator.filter(|_| mutated_1 || mutated_2),
+                    reward,
+                });
             }
-            
-        });
-        handles.push(handle);
-    }
 
-    //Joining all the threads
-    for handle in handles{
-        handle.join().unwrap();
-    }
+            //Adds the children in the offspring
+            offspring_t.push(child_1);
+            offspring_t.push(child_2);
+            
+            //Then sets the offspring in the result vector, with their parents
+            offspring.individuals.append(&mut offspring_t);
+            offspring.parents.extend([(*key, *value), (*key, *value)]);
+        }
+        offspring
+    });
 
     debug!(target="ga_events", method="parent_crossover"; "Parent crossover finished");
-    return offspring.lock().unwrap().to_vec();
+    //Joins the offspring of the threads, in the order of the couples
+    let mut offspring = Offspring { individuals: Vec::new(), parents: Vec::new(), applications: Vec::new(), evaluations: Evaluations::default() };
+    for mut offspring_t in offsprings {
+        offspring.individuals.append(&mut offspring_t.individuals);
+        offspring.parents.append(&mut offspring_t.parents);
+        offspring.applications.append(&mut offspring_t.applications);
+        offspring.evaluations.add(offspring_t.evaluations);
+    }
+    offspring
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
+use std::cmp::Ordering;
 use rand::Rng;
 
-use crate::{configuration::GaConfiguration, population::Population, traits::{GenotypeT, GeneT}, operations::{self, survivor::fitness::ProblemSolving}};
+use crate::{configuration::{GaConfiguration, LimitConfiguration}, population::Population, traits::{GenotypeT, GeneT}, operations::{self, survivor::fitness::ProblemSolving}, domain::{Domain, locus_domain}};
 
 pub mod condition_checker;
 
@@ -8,7 +9,7 @@ pub mod condition_checker;
  * Function to call the different condition checkers 
  */
 pub fn condition_checker_factory<U>(configuration: Option<&GaConfiguration>, population: Option<&Population<U>>, 
-                                    alleles: Option<&[U::Gene]>, default_population: bool)
+                                    alleles: Option<&[U::Gene]>, domains: &[Domain<U::Gene>], default_population: bool)
 where
 U: GenotypeT + Send + Sync + 'static + Clone
 {
@@ -20,45 +21,128 @@ U: GenotypeT + Send + Sync + 'static + Clone
     //2- Checks the configuration
     if let Some(configuration) = configuration {
 
-        //2.1- We call the condition for fixed fitness
-        if configuration.limit_configuration.problem_solving == ProblemSolving::FixedFitness{
-            condition_checker::fitness_target_is_some(configuration, configuration.limit_configuration.problem_solving.to_string());
-        }
-
-        //2.2- Checks the population
+        //2.1- Checks the population
         if let Some(population) = population {
 
-            //2.2.1- Checks the conditions for cycle crossover operation
-            if configuration.crossover_configuration.method == operations::Crossover::Cycle{
+            //2.1.1- Checks the conditions for cycle crossover operation
+            if configuration.crossover_configuration.method == operations::Crossover::Cycle ||
+               (configuration.operator_selection_configuration.method.is_some() && configuration.operator_selection_configuration.crossover_operators.contains(&operations::Crossover::Cycle)){
                 condition_checker::unique_gene_ids(population);
             }
         }
 
-        //2.3- Condition checkers for the adaptive genetic algorithms
-        if configuration.adaptive_ga{
-            //2.3.1- Checks for the crossover parameters
-            condition_checker::aga_crossover_probabilities(configuration);
-        } 
-
-        //2.4- Condition checkers for the repetition of the alleles
-        if configuration.limit_configuration.alleles_can_be_repeated{
+        //2.2- Condition checkers for the repetition of the alleles, only needed to initialize the population randomly from the alleles
+        if !configuration.limit_configuration.alleles_can_be_repeated && default_population && domains.is_empty(){
             if let Some(alleles) = alleles {
                 condition_checker::check_genotype_length_not_bigger_than_alleles::<U>(alleles, configuration.limit_configuration.genes_per_individual);
             }
         }
 
-        //2.5- Condition checkers for the default population
+        //2.3- Condition checkers for the default population
         if default_population{
             condition_checker::check_genes_per_individual_is_set(configuration);
             condition_checker::check_population_size_is_set(configuration);
-            condition_checker::check_alleles_are_set::<U>(alleles);
+            if domains.is_empty() {
+                condition_checker::check_alleles_are_set::<U>(alleles);
+            }
         } 
 
-        //2.6- Condition checker for the couples
-        condition_checker::check_number_of_couples_is_set(configuration);
+        //2.4- Condition checker for the domains of the loci
+        condition_checker::domains::<U>(domains, configuration);
+
+        //2.5- Condition checker for the genes of the fitness cache, when their ids are set to the loci
+        if configuration.fitness_cache_size.is_some() && configuration.limit_configuration.needs_unique_ids {
+            let mut genes: Vec<U::Gene> = if domains.is_empty() {
+                alleles.map(|alleles| alleles.to_vec()).unwrap_or_default()
+            } else {
+                domains.iter().map(|domain| domain.quantile(0.5)).collect()
+            };
+            if let Some(population) = population {
+                genes.extend(population.individuals.iter().flat_map(|individual| individual.get_dna().iter().cloned()));
+            }
+            if let Err(message) = condition_checker::fitness_cache_genes(&genes) {
+                panic!("{}", message);
+            }
+        }
+
+        //2.6- Condition checkers that only depend on the configuration, with the size of the population if it's set
+        let population_size = match population {
+            Some(population) if population.size() > 0 => population.size() as i32,
+            _ => configuration.limit_configuration.population_size,
+        };
+        if let Err(message) = configuration_checker(configuration, population_size) {
+            panic!("{}", message);
+        }
     } 
 }
 
+/**
+ * Function to call the condition checkers that only depend on the configuration, returning the first condition not met.
+ * They are used both before the run and to validate the configuration.
+ */
+pub fn configuration_checker(configuration: &GaConfiguration, population_size: i32) -> Result<(), String>
+{
+    //1- We call the condition for fixed fitness
+    if configuration.limit_configuration.problem_solving == ProblemSolving::FixedFitness{
+        condition_checker::fitness_target_is_some(configuration, configuration.limit_configuration.problem_solving.to_string())?;
+    }
+
+    //2- Condition checkers for the adaptive genetic algorithms
+    if configuration.adaptive_ga{
+        //2.1- Checks for the crossover parameters
+        condition_checker::aga_crossover_probabilities(configuration)?;
+
+        //2.2- Checks for the mutation parameters, if the mutation probability is set
+        if configuration.mutation_configuration.probability_max.is_some(){
+            condition_checker::aga_mutation_probabilities(configuration)?;
+        }
+    } 
+
+    //3- Condition checkers for the couples and the crossover
+    condition_checker::check_number_of_couples_is_set(configuration)?;
+    condition_checker::crossover_number_of_points(configuration)?;
+
+    //4- Condition checker for the parameter control schedules
+    condition_checker::schedule_parameters(configuration.crossover_configuration.schedule, "crossover_configuration")?;
+    condition_checker::schedule_parameters(configuration.mutation_configuration.schedule, "mutation_configuration")?;
+
+    //5- Condition checker for the adaptive operator selection
+    if configuration.operator_selection_configuration.method.is_some(){
+        condition_checker::operator_selection_portfolios(configuration)?;
+    }
+
+    //6- Condition checker for the (mu, lambda) and (mu + lambda) strategies
+    if configuration.lambda.is_some() || configuration.survivor == operations::Survivor::MuCommaLambda{
+        condition_checker::check_lambda(configuration, population_size)?;
+    }
+
+    //7- Condition checker for the restricted tournament replacement
+    if configuration.survivor == operations::Survivor::RestrictedTournament{
+        condition_checker::check_window_size_is_set(configuration)?;
+    }
+
+    //8- Condition checker for the fitness sharing and clearing
+    if configuration.niching_configuration.method.is_some(){
+        condition_checker::niching_parameters(configuration)?;
+    }
+
+    //9- Condition checker for the steady state
+    if configuration.steady_state_configuration.steady_state{
+        condition_checker::check_offspring_per_step_is_set(configuration)?;
+    }
+
+    //10- Condition checker for the history export
+    if configuration.history_configuration.export_history{
+        condition_checker::check_history_path_is_set(configuration)?;
+    }
+
+    //11- Condition checker for the fitness cache
+    if configuration.fitness_cache_size.is_some(){
+        condition_checker::fitness_cache(configuration)?;
+    }
+    Ok(())
+}
+
 /**
  * Function to initialize the dna of an individual without repeating an array of alleles
  */
@@ -66,7 +150,7 @@ pub fn initialize_dna_without_repeated_alleles<U>(alleles: &[U::Gene], genes_per
 where
 U: GenotypeT + Send + Sync + 'static + Clone{
     
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     let mut dna = Vec::new();
 
     let mut tmp_alleles = alleles.to_vec().clone();
@@ -96,7 +180,7 @@ pub fn initialize_dna<U>(alleles: &[U::Gene], genes_per_individual: i32, needs_u
 where
 U: GenotypeT + Send + Sync + 'static + Clone{
     
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     let mut dna = Vec::new();
 
     //Selects the genes randomly from the vector without repeating them
@@ -113,4 +197,78 @@ U: GenotypeT + Send + Sync + 'static + Clone{
     }
 
     dna
+}
+
+/**
+ * Function to initialize the dna of an individual from the domain of each locus
+ */
+pub fn initialize_dna_from_domains<U>(domains: &[Domain<U::Gene>], genes_per_individual: i32, needs_unique_ids: bool)->Vec<U::Gene>
+where
+U: GenotypeT + Send + Sync + 'static + Clone{
+
+    let mut rng = crate::rng::rng();
+    let mut dna = Vec::new();
+
+    //Each gene is taken randomly from the domain of its locus
+    for j in 0..genes_per_individual{
+        let mut gene = locus_domain(domains, j as usize).sample(&mut rng);
+
+        //If we need unique ids
+        if needs_unique_ids {
+            gene.set_id(j);
+        }
+
+        dna.push(gene);
+    }
+
+    dna
+}
+
+/**
+ * Function to get a random number from the standard normal distribution (Box-Muller transform)
+ */
+pub fn standard_normal<R: Rng>(rng: &mut R) -> f64{
+    let u_1: f64 = 1.0 - rng.gen_range(0.0..1.0);
+    let u_2: f64 = rng.gen_range(0.0..1.0);
+    (-2.0 * u_1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u_2).cos()
+}
+
+/**
+ * Function to compare two individuals depending on the problem solving. Returns `Ordering::Less` if the first individual is better,
+ * so sorting with this comparator puts the best individuals first. Individuals with a NaN fitness are the worst ones.
+ */
+pub fn fitness_ordering<U: GenotypeT>(individual_1: &U, individual_2: &U, limit_configuration: &LimitConfiguration) -> Ordering{
+    fitness_loss(individual_1, limit_configuration).total_cmp(&fitness_loss(individual_2, limit_configuration))
+}
+
+/**
+ * Function to get the score of an individual for the adaptive genetic algorithms: the fitness in maximization problems, 
+ * and the opposite of the fitness or of the distance to the target otherwise, so the higher the better in every problem
+ */
+pub fn fitness_score<U: GenotypeT>(individual: &U, limit_configuration: &LimitConfiguration) -> f64{
+    -fitness_loss(individual, limit_configuration)
+}
+
+/**
+ * Function to get the Srinivas-Patnaik ratio (f_max - f) / (f_max - f_avg) of a score, between 0.0 and 1.0.
+ * When the best score is the average one, the population has converged and the ratio is 1.0.
+ */
+pub fn aga_ratio(score: f64, f_max: f64, f_avg: f64) -> f64{
+    if f_max - f_avg <= f64::EPSILON * f_max.abs().max(1.0) {
+        1.0
+    } else {
+        ((f_max - score) / (f_max - f_avg)).clamp(0.0, 1.0)
+    }
+}
+
+/**
+ * Function to get how far an individual is from the optimum, the lower the better
+ */
+fn fitness_loss<U: GenotypeT>(individual: &U, limit_configuration: &LimitConfiguration) -> f64{
+    let loss = match limit_configuration.problem_solving {
+        ProblemSolving::Maximization => -individual.get_fitness(),
+        ProblemSolving::Minimization => individual.get_fitness(),
+        ProblemSolving::FixedFitness => individual.get_fitness_distance(&limit_configuration.fitness_target.unwrap()),
+    };
+    if loss.is_nan() {f64::INFINITY} else {loss}
 }
\ No newline at end of file
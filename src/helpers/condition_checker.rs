@@ -1,6 +1,6 @@
 use core::panic;
 
-use crate::{population::Population, traits::{GenotypeT, GeneT}, configuration::GaConfiguration};
+use crate::{population::Population, traits::{GenotypeT, GeneT}, configuration::{GaConfiguration, Schedule}, operations::{Crossover, Mutation, Survivor, Niching}, domain::Domain};
 
 /**
  * Function to check that every individual has unique id's within their dna
@@ -27,12 +27,13 @@ U:GenotypeT + Send + Sync + 'static + Clone{
 /**
  * This function checks that fitness target is not none
  */
-pub fn fitness_target_is_some(configuration: &GaConfiguration, problem_type: String){
+pub fn fitness_target_is_some(configuration: &GaConfiguration, problem_type: String) -> Result<(), String>{
 
     //Checks that the fitness target is some
     if configuration.limit_configuration.fitness_target.is_none(){
-        panic!("For {} problems, fitness_target must be set.", problem_type);
+        return Err(format!("For {} problems, fitness_target must be set.", problem_type));
     }
+    Ok(())
 }
 
 /**
@@ -55,23 +56,25 @@ U:GenotypeT + Send + Sync + 'static + Clone{
 /**
  * Checks that for adaptive crossover all the requirements are set
  */
-pub fn aga_crossover_probabilities(configuration: &GaConfiguration){
+pub fn aga_crossover_probabilities(configuration: &GaConfiguration) -> Result<(), String>{
     if configuration.crossover_configuration.probability_max.is_none() || configuration.crossover_configuration.probability_min.is_none() {
-        panic!("For Adaptive Genetic Algorithms, the probability_max and probability_min in the crossover_configuration are mandatory.");
+        return Err("For Adaptive Genetic Algorithms, the probability_max and probability_min in the crossover_configuration are mandatory.".to_string());
     }else if configuration.crossover_configuration.probability_max <=  configuration.crossover_configuration.probability_min {
-        panic!("For Adaptive Genetic Algorithms, the probability_max must be greater than probability_min in the crossover_configuration.");
+        return Err("For Adaptive Genetic Algorithms, the probability_max must be greater than probability_min in the crossover_configuration.".to_string());
     }
+    Ok(())
 }
 
 /**
  * Checks that for adaptive mutation all the requirements are set
  */
-pub fn aga_mutation_probabilities(configuration: GaConfiguration){
+pub fn aga_mutation_probabilities(configuration: &GaConfiguration) -> Result<(), String>{
     if configuration.mutation_configuration.probability_max.is_none() || configuration.mutation_configuration.probability_min.is_none(){
-        panic!("For Adaptive Genetic Algorithms, the probability_max and probability_min in the mutation_configuration are mandatory.");
+        return Err("For Adaptive Genetic Algorithms, the probability_max and probability_min in the mutation_configuration are mandatory.".to_string());
     }else if configuration.mutation_configuration.probability_max <= configuration.mutation_configuration.probability_min {
-        panic!("For Adaptive Genetic Algorithms, the probability_max must be greater than probability_min in the mutation_configuration.");
+        return Err("For Adaptive Genetic Algorithms, the probability_max must be greater than probability_min in the mutation_configuration.".to_string());
     }
+    Ok(())
 }
 
 /**
@@ -120,8 +123,162 @@ where U:GenotypeT + Send + Sync + 'static + Clone{
 /**
  * Function to check that the number of couples is set
  */
-pub fn check_number_of_couples_is_set(configuration: &GaConfiguration){
+pub fn check_number_of_couples_is_set(configuration: &GaConfiguration) -> Result<(), String>{
     if configuration.selection_configuration.number_of_couples <= 0 {
-        panic!("The number of couples must be set.");
+        return Err("The number of couples must be set.".to_string());
     }
+    Ok(())
+}
+
+/**
+ * Function to check that the number of points is set for the multipoint crossover
+ */
+pub fn crossover_number_of_points(configuration: &GaConfiguration) -> Result<(), String>{
+    if configuration.crossover_configuration.method == Crossover::MultiPoint && configuration.crossover_configuration.number_of_points.unwrap_or(0) <= 0 {
+        return Err("For the multipoint crossover, the number_of_points in the crossover_configuration must be greater than 0.".to_string());
+    }
+    Ok(())
+}
+
+
+/**
+ * Function to check that the portfolios of the adaptive operator selection are valid
+ */
+pub fn operator_selection_portfolios(configuration: &GaConfiguration) -> Result<(), String>{
+    let operator_selection = &configuration.operator_selection_configuration;
+    if operator_selection.crossover_operators.is_empty() && operator_selection.mutation_operators.is_empty() {
+        return Err("For adaptive operator selection, at least one crossover or mutation operator must be set in the portfolios.".to_string());
+    }
+
+    //The minimum probability must leave room for the selection of the best operator
+    for portfolio_size in [operator_selection.crossover_operators.len(), operator_selection.mutation_operators.len()] {
+        if portfolio_size as f64 * operator_selection.probability_min >= 1.0 {
+            return Err("For adaptive operator selection, the probability_min multiplied by the number of operators must be lower than 1.0.".to_string());
+        }
+    }
+
+    if operator_selection.crossover_operators.contains(&Crossover::MultiPoint) && configuration.crossover_configuration.number_of_points.is_none() {
+        return Err("For adaptive operator selection with the multipoint crossover, the number_of_points in the crossover_configuration is mandatory.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check that the domains of the loci are valid, and that they are set for the random resetting mutation
+ */
+pub fn domains<U>(domains: &[Domain<U::Gene>], configuration: &GaConfiguration)
+where U:GenotypeT + Send + Sync + 'static + Clone{
+    let genes_per_individual = configuration.limit_configuration.genes_per_individual;
+    if domains.len() > 1 && domains.len() != genes_per_individual as usize {
+        panic!("There must be one domain for every locus or one domain per locus. The individuals have {} genes and there are {} domains.", genes_per_individual, domains.len());
+    }
+    if let Some(locus) = domains.iter().position(|domain| domain.is_empty()) {
+        panic!("The domain of the locus {} has no values.", locus);
+    }
+
+    let uses_random_resetting = configuration.mutation_configuration.method == Mutation::RandomResetting ||
+        (configuration.operator_selection_configuration.method.is_some() && configuration.operator_selection_configuration.mutation_operators.contains(&Mutation::RandomResetting));
+    if uses_random_resetting && domains.is_empty() {
+        panic!("The random resetting mutation needs the domains of the loci.");
+    }
+}
+
+/**
+ * Function to check that the parameters of a schedule are valid
+ */
+pub fn schedule_parameters(schedule: Schedule, configuration_name: &str) -> Result<(), String>{
+    match schedule {
+        Schedule::Exponential { rate } if rate <= 0.0 => {
+            Err(format!("The rate of the exponential schedule in the {} must be greater than 0.", configuration_name))
+        },
+        Schedule::Step { step_size, factor } if step_size <= 0 || factor <= 0.0 || factor > 1.0 => {
+            Err(format!("The step schedule in the {} must have a step_size greater than 0 and a factor between 0.0 (exclusive) and 1.0.", configuration_name))
+        },
+        _ => Ok(()),
+    }
+}
+
+/**
+ * Function to check that the number of offspring per step is set for the steady state
+ */
+pub fn check_offspring_per_step_is_set(configuration: &GaConfiguration) -> Result<(), String>{
+    if configuration.steady_state_configuration.offspring_per_step <= 0 {
+        return Err("For steady state, the number of offspring per step must be set.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check the number of children per generation (lambda)
+ */
+pub fn check_lambda(configuration: &GaConfiguration, population_size: i32) -> Result<(), String>{
+    match configuration.lambda {
+        Some(lambda) if lambda <= 0 => Err("The lambda must be greater than 0.".to_string()),
+        Some(lambda) if configuration.survivor == Survivor::MuCommaLambda && lambda < population_size => 
+            Err("For the (mu, lambda) survivor, the lambda must be greater or equal than the population size.".to_string()),
+        None if configuration.survivor == Survivor::MuCommaLambda => Err("For the (mu, lambda) survivor, the lambda must be set.".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/**
+ * Function to check that the window size is set for the restricted tournament replacement
+ */
+pub fn check_window_size_is_set(configuration: &GaConfiguration) -> Result<(), String>{
+    if configuration.niching_configuration.window_size <= 0 {
+        return Err("For the restricted tournament survivor, the window size must be greater than 0.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check the parameters of the fitness sharing and clearing
+ */
+pub fn niching_parameters(configuration: &GaConfiguration) -> Result<(), String>{
+    let niching_configuration = configuration.niching_configuration;
+    if niching_configuration.sigma_share <= 0.0 {
+        return Err("For the niching methods, the sigma share must be greater than 0.".to_string());
+    }
+    if niching_configuration.method == Some(Niching::FitnessSharing) && niching_configuration.alpha <= 0.0 {
+        return Err("For the fitness sharing, the alpha must be greater than 0.".to_string());
+    }
+    if niching_configuration.method == Some(Niching::Clearing) && niching_configuration.niche_capacity <= 0 {
+        return Err("For the clearing, the niche capacity must be greater than 0.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check that the history path is set for the history export
+ */
+pub fn check_history_path_is_set(configuration: &GaConfiguration) -> Result<(), String>{
+    if configuration.history_configuration.history_path.is_empty() {
+        return Err("For the history export, the history path must be set.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check the size of the fitness cache
+ */
+pub fn fitness_cache(configuration: &GaConfiguration) -> Result<(), String>{
+    if configuration.fitness_cache_size.unwrap_or(0) <= 0 {
+        return Err("The size of the fitness cache must be greater than 0.".to_string());
+    }
+    Ok(())
+}
+
+/**
+ * Function to check that the genes are told apart in the fitness cache when they need unique ids. The ids are set to the loci,
+ * so the values of the genes can't follow their ids, as the values of the genes without a numeric value do.
+ */
+pub fn fitness_cache_genes<T: GeneT>(genes: &[T]) -> Result<(), String>{
+    for gene in genes {
+        let mut relabeled = gene.clone();
+        relabeled.set_id(gene.get_id().wrapping_add(1));
+        if relabeled.get_value().to_bits() != gene.get_value().to_bits() {
+            return Err("The fitness cache is keyed by the ids and the values of the genes, and the values of these genes follow their ids, so it can't be used when the genes need unique ids.".to_string());
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,43 @@
+use std::fs;
+use log::debug;
+use crate::{configuration::{HistoryConfiguration, HistoryFormat}, statistics::{self, GenerationStatistics, json_number}, traits::{GenotypeT, GeneT}};
+
+/**
+ * Function to write the statistics history of a run, and the DNA of the best individual if its path is set
+ */
+pub fn export<U: GenotypeT>(statistics: &[GenerationStatistics], best_individual: &U, configuration: &HistoryConfiguration) -> std::io::Result<()>{
+
+    //1- Writes the history with one record per generation
+    let history = match configuration.history_format {
+        HistoryFormat::Csv => statistics::to_csv(statistics),
+        HistoryFormat::JsonLines => statistics::to_json_lines(statistics),
+    };
+    fs::write(&configuration.history_path, history)?;
+    debug!(target="history_events", method="export"; "History written in {}", configuration.history_path);
+
+    //2- Writes the best individual
+    if let Some(best_individual_path) = &configuration.best_individual_path {
+        fs::write(best_individual_path, best_individual_to_string(best_individual, configuration.history_format))?;
+        debug!(target="history_events", method="export"; "Best individual written in {}", best_individual_path);
+    }
+    Ok(())
+}
+
+/**
+ * Function to write the DNA of an individual: one row per gene in CSV, or a JSON object with the fitness and the genes
+ */
+pub fn best_individual_to_string<U: GenotypeT>(individual: &U, history_format: HistoryFormat) -> String{
+    match history_format {
+        HistoryFormat::Csv => {
+            let mut csv = String::from("locus,id,value\n");
+            for (locus, gene) in individual.get_dna().iter().enumerate() {
+                csv.push_str(&format!("{},{},{}\n", locus, gene.get_id(), gene.get_value()));
+            }
+            csv
+        },
+        HistoryFormat::JsonLines => {
+            let dna: Vec<String> = individual.get_dna().iter().map(|gene| format!("{{\"id\":{},\"value\":{}}}", gene.get_id(), json_number(gene.get_value()))).collect();
+            format!("{{\"fitness\":{},\"dna\":[{}]}}\n", json_number(individual.get_fitness()), dna.join(","))
+        },
+    }
+}
\ No newline at end of file
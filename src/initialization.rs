@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+use crate::{configuration::LimitConfiguration, domain::Domain, helpers, traits::{GenotypeT, GeneT}};
+
+pub mod random;
+pub mod latin_hypercube;
+pub mod opposition;
+pub mod heuristic;
+pub mod seeded;
+
+/**
+ * Strategy to create the initial population of the random initialization
+ */
+pub trait Initializer<U>: Send + Sync
+where
+    U: GenotypeT
+{
+    //Creates the individuals of the initial population
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U>;
+
+    //Whether the fitness of the individuals is calculated in the initialization. Otherwise, it's calculated at the start of the run
+    fn evaluates_fitness(&self) -> bool {
+        false
+    }
+}
+
+/**
+ * Information of the Ga for the initialization of the population
+ */
+pub struct InitializationContext<'a, U>
+where
+    U: GenotypeT
+{
+    pub population_size: usize,
+    pub genes_per_individual: usize,
+    pub alleles: &'a [U::Gene],
+    pub domains: &'a [Domain<U::Gene>],
+    pub alleles_can_be_repeated: bool,
+    pub needs_unique_ids: bool,
+    pub limit_configuration: &'a LimitConfiguration,
+    //Individual cloned for the new individuals, instead of a new one
+    pub prototype: Option<&'a U>,
+}
+
+impl<U> InitializationContext<'_, U>
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    /**
+     * Function to get a random dna, from the domains if they are set and from the alleles otherwise
+     */
+    pub fn random_dna(&self) -> Vec<U::Gene> {
+        let genes_per_individual = self.genes_per_individual as i32;
+        if !self.domains.is_empty() {
+            helpers::initialize_dna_from_domains::<U>(self.domains, genes_per_individual, self.needs_unique_ids)
+        } else if self.alleles_can_be_repeated {
+            helpers::initialize_dna::<U>(self.alleles, genes_per_individual, self.needs_unique_ids)
+        } else {
+            helpers::initialize_dna_without_repeated_alleles::<U>(self.alleles, genes_per_individual, self.needs_unique_ids)
+        }
+    }
+
+    /**
+     * Function to create an individual with the given dna, with age 0 and its fitness calculated.
+     * If the ids must be unique, they are set to the loci.
+     */
+    pub fn individual(&self, dna: &[U::Gene]) -> U {
+        let mut dna = dna.to_vec();
+        if self.needs_unique_ids {
+            dna.iter_mut().enumerate().for_each(|(locus, gene)| {gene.set_id(locus as i32);});
+        }
+
+        let mut individual = self.prototype.cloned().unwrap_or_else(U::new);
+        individual.set_dna(&dna);
+        individual.set_age(0);
+        individual.calculate_fitness();
+        individual
+    }
+
+    /**
+     * Function to create a random individual
+     */
+    pub fn random_individual(&self) -> U {
+        self.individual(&self.random_dna())
+    }
+
+    /**
+     * Function to get the domains of the loci, or a discrete domain of the alleles shared by every locus if they are not set
+     */
+    pub fn locus_domains(&self) -> Cow<'_, [Domain<U::Gene>]> {
+        if self.domains.is_empty() {
+            Cow::Owned(vec![Domain::Discrete(self.alleles.to_vec())])
+        } else {
+            Cow::Borrowed(self.domains)
+        }
+    }
+}
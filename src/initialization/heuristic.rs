@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use log::debug;
+use crate::traits::GenotypeT;
+use super::{Initializer, InitializationContext};
+
+/**
+ * Heuristic seeding: the first individuals are built by a heuristic, given the index of the individual,
+ * and the rest of the population is filled randomly
+ */
+pub struct Heuristic<U>
+where
+    U: GenotypeT
+{
+    heuristic: Arc<dyn Fn(usize) -> Vec<U::Gene> + Send + Sync>,
+    //Number of individuals built by the heuristic
+    pub count: usize,
+}
+
+impl<U> Heuristic<U>
+where
+    U: GenotypeT
+{
+    /**
+     * Creates the seeding from the heuristic, building the dna of the given number of individuals
+     */
+    pub fn new<F>(count: usize, heuristic: F) -> Self
+    where F: Fn(usize) -> Vec<U::Gene> + Send + Sync + 'static {
+        Heuristic { heuristic: Arc::new(heuristic), count }
+    }
+}
+
+impl<U> Initializer<U> for Heuristic<U>
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U> {
+        debug!(target="initialization_events", method="heuristic"; "Starting the heuristic seeding of {} individuals", self.count);
+        (0..context.population_size).map(|index| {
+            if index < self.count {context.individual(&(self.heuristic)(index))} else {context.random_individual()}
+        }).collect()
+    }
+
+    fn evaluates_fitness(&self) -> bool {
+        true
+    }
+}
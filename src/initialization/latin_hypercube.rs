@@ -0,0 +1,41 @@
+use rand::{Rng, seq::SliceRandom};
+use log::debug;
+use crate::{traits::GenotypeT, domain::locus_domain};
+use super::{Initializer, InitializationContext};
+
+/**
+ * Latin hypercube initialization: the domain of each locus is split in as many strata as individuals,
+ * and every stratum is sampled once, in a random order for each locus. Without domains, the alleles are the domain of every locus,
+ * so the alleles can be repeated within the dna.
+ */
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LatinHypercube;
+
+impl<U> Initializer<U> for LatinHypercube
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U> {
+        debug!(target="initialization_events", method="latin_hypercube"; "Starting the latin hypercube initialization");
+        let mut rng = crate::rng::rng();
+        let size = context.population_size;
+        let domains = context.locus_domains();
+        let mut dnas = vec![Vec::with_capacity(context.genes_per_individual); size];
+
+        //Each locus gets a random permutation of the strata
+        for locus in 0..context.genes_per_individual {
+            let domain = locus_domain(&domains, locus);
+            let mut strata: Vec<usize> = (0..size).collect();
+            strata.shuffle(&mut rng);
+            for (dna, stratum) in dnas.iter_mut().zip(strata) {
+                dna.push(domain.quantile((stratum as f64 + rng.gen_range(0.0..1.0)) / size as f64));
+            }
+        }
+
+        dnas.iter().map(|dna| context.individual(dna)).collect()
+    }
+
+    fn evaluates_fitness(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,39 @@
+use log::debug;
+use crate::{traits::GenotypeT, domain::locus_domain, helpers::fitness_ordering};
+use super::{Initializer, InitializationContext};
+
+/**
+ * Opposition-based initialization: every random individual competes with its opposite, reflected within the domain of each locus,
+ * and the best half of both is kept. Without domains, the opposite of an allele is the allele in the reflected position,
+ * so the dna without repeated alleles keeps them unique.
+ */
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OppositionBased;
+
+impl<U> Initializer<U> for OppositionBased
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U> {
+        debug!(target="initialization_events", method="opposition_based"; "Starting the opposition-based initialization");
+        let domains = context.locus_domains();
+
+        //1- Random individuals and their opposites
+        let mut individuals = Vec::with_capacity(2 * context.population_size);
+        for _ in 0..context.population_size {
+            let dna = context.random_dna();
+            let opposite: Vec<U::Gene> = dna.iter().enumerate().map(|(locus, gene)| locus_domain(&domains, locus).opposite(gene)).collect();
+            individuals.push(context.individual(&dna));
+            individuals.push(context.individual(&opposite));
+        }
+
+        //2- The best individuals are kept
+        individuals.sort_by(|individual_1, individual_2| fitness_ordering(individual_1, individual_2, context.limit_configuration));
+        individuals.truncate(context.population_size);
+        individuals
+    }
+
+    fn evaluates_fitness(&self) -> bool {
+        true
+    }
+}
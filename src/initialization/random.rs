@@ -0,0 +1,23 @@
+use log::debug;
+use crate::traits::GenotypeT;
+use super::{Initializer, InitializationContext};
+
+/**
+ * Random initialization, from the domains of the loci or from the alleles
+ */
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Random;
+
+impl<U> Initializer<U> for Random
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U> {
+        debug!(target="initialization_events", method="random"; "Starting the random initialization");
+        (0..context.population_size).map(|_| context.random_individual()).collect()
+    }
+
+    fn evaluates_fitness(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,51 @@
+use log::debug;
+use crate::traits::GenotypeT;
+use super::{Initializer, InitializationContext};
+
+/**
+ * Seeded initialization: the given individuals start the population, and the rest of the population is filled randomly.
+ * If there are more seeds than individuals, only the first ones are taken.
+ */
+#[derive(Clone, Debug, Default)]
+pub struct Seeded<U>
+where
+    U: GenotypeT
+{
+    pub seeds: Vec<U>,
+}
+
+impl<U> Seeded<U>
+where
+    U: GenotypeT
+{
+    /**
+     * Creates the seeded initialization from the seed individuals
+     */
+    pub fn new(seeds: Vec<U>) -> Self {
+        Seeded { seeds }
+    }
+}
+
+impl<U> Initializer<U> for Seeded<U>
+where
+    U: GenotypeT + Send + Sync + 'static + Clone
+{
+    fn initialize(&self, context: &InitializationContext<U>) -> Vec<U> {
+        debug!(target="initialization_events", method="seeded"; "Starting the seeded initialization with {} seeds", self.seeds.len());
+        let mut individuals: Vec<U> = self.seeds.iter().take(context.population_size).map(|seed| {
+            let mut individual = seed.clone();
+            individual.set_age(0);
+            individual.calculate_fitness();
+            individual
+        }).collect();
+
+        while individuals.len() < context.population_size {
+            individuals.push(context.random_individual());
+        }
+        individuals
+    }
+
+    fn evaluates_fitness(&self) -> bool {
+        true
+    }
+}
@@ -3,4 +3,16 @@ pub mod traits;
 pub mod population;
 pub mod ga;
 pub mod configuration;
-pub mod helpers;
\ No newline at end of file
+pub mod helpers;
+pub mod schedule;
+pub mod distance;
+pub mod statistics;
+pub mod history;
+pub mod rng;
+pub mod experiment;
+pub mod comparison;
+pub mod problems;
+pub mod domain;
+pub mod initialization;
+pub mod parallel;
+pub mod cache;
@@ -2,28 +2,64 @@ pub mod selection;
 pub mod crossover;
 pub mod mutation;
 pub mod survivor;
+pub mod operator_selection;
+pub mod replacement;
+pub mod niching;
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Selection {
     Random,
     RouletteWheel,
     StochasticUniversalSampling,
     Tournament
 }
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Crossover {
     Cycle,
     MultiPoint,
     Uniform,
 }
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mutation {
     Swap,
     Inversion,
     Scramble,
+    Gaussian,
+    Creep,
+    RandomResetting,
 }
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Survivor {
     Fitness,
     Age,
+    MuPlusLambda,
+    MuCommaLambda,
+    DeterministicCrowding,
+    RestrictedTournament,
+}
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OperatorSelection {
+    ProbabilityMatching,
+    AdaptivePursuit,
+    UpperConfidenceBound,
+}
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Replacement {
+    Worst,
+    Random,
+    Oldest,
+    ParentIfBetter,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Niching {
+    FitnessSharing,
+    Clearing,
 }
\ No newline at end of file
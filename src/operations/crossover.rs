@@ -1,5 +1,6 @@
 pub(crate) use super::Crossover;
-use crate::configuration::CrossoverConfiguration;
+use crate::configuration::{CrossoverConfiguration, LimitConfiguration, ProblemSolving};
+use crate::helpers::{fitness_score, aga_ratio};
 use crate::traits::GenotypeT;
 use self::cycle::cycle;
 use self::multipoint::multipoint_crossover;
@@ -10,20 +11,53 @@ pub mod multipoint;
 pub mod uniform_crossover;
 
 pub fn factory<U: GenotypeT>(parent_1: &U, parent_2: &U, configuration: CrossoverConfiguration) -> Option<Vec<U>>{
-    match configuration.method {
+    let mut offspring = match configuration.method {
         Crossover::Cycle => {cycle(parent_1, parent_2)},
         Crossover::MultiPoint => {multipoint_crossover(parent_1, parent_2, &configuration.number_of_points.unwrap())},
         Crossover::Uniform => {uniform(parent_1, parent_2)},
+    }?;
+
+    //Self-adaptive children get the intermediate recombination of the strategy parameters of their parents
+    let (strategy_parameters_1, strategy_parameters_2) = (parent_1.get_strategy_parameters(), parent_2.get_strategy_parameters());
+    if !strategy_parameters_1.is_empty() && strategy_parameters_1.len() == strategy_parameters_2.len() {
+        let strategy_parameters: Vec<f64> = strategy_parameters_1.iter().zip(strategy_parameters_2).map(|(sigma_1, sigma_2)| (sigma_1 + sigma_2) / 2.0).collect();
+        for child in offspring.iter_mut(){
+            child.set_strategy_parameters(&strategy_parameters);
+        }
+    }
+
+    //The fitness of the children is not valid until it's calculated
+    for child in offspring.iter_mut(){
+        child.set_fitness_valid(false);
     }
+
+    Some(offspring)
 }
 
-//Function to calculate the probability for adaptive genetic algorithms
+//Function to calculate the probability for adaptive genetic algorithms, over the fitness as in a maximization problem
 pub fn aga_probability<U: GenotypeT>(parent_1: &U, parent_2: &U, f_max: f64, f_avg: f64, probability_max: f64, probability_min: f64)->f64{
-    let larger_f = if parent_1.get_fitness() > parent_2.get_fitness() {parent_1.get_fitness()}else{parent_2.get_fitness()};
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+    aga_probability_with_limit_configuration(parent_1, parent_2, f_max, f_avg, probability_max, probability_min, &maximization)
+}
+
+//Function to calculate the probability for adaptive genetic algorithms, over the scores of the problem of the limit configuration
+pub fn aga_probability_with_limit_configuration<U: GenotypeT>(parent_1: &U, parent_2: &U, f_max: f64, f_avg: f64, probability_max: f64, probability_min: f64, limit_configuration: &LimitConfiguration)->f64{
+    let larger_f = fitness_score(parent_1, limit_configuration).max(fitness_score(parent_2, limit_configuration));
 
     if larger_f >= f_avg {
-        probability_max*((f_max - larger_f) / (f_max - f_avg))
+        probability_max * aga_ratio(larger_f, f_max, f_avg)
     }else{
         probability_min
     }
+}
+
+//Function to calculate the probability for the Srinivas-Patnaik adaptive genetic algorithms, with k1 = k3 = probability_max
+pub fn srinivas_patnaik_probability<U: GenotypeT>(parent_1: &U, parent_2: &U, f_max: f64, f_avg: f64, probability_max: f64, probability_min: f64, limit_configuration: &LimitConfiguration)->f64{
+    let larger_f = fitness_score(parent_1, limit_configuration).max(fitness_score(parent_2, limit_configuration));
+
+    if larger_f >= f_avg {
+        (probability_max * aga_ratio(larger_f, f_max, f_avg)).max(probability_min)
+    }else{
+        probability_max
+    }
 }
\ No newline at end of file
@@ -9,15 +9,16 @@ pub fn uniform<U: GenotypeT>(parent_1: &U, parent_2: &U) -> Option<Vec<U>>{
         panic!("parent 1 and parent 2 must have the same dna length. Currently parent 1 has a length of {} and parent 2 {}", parent_1.get_dna().len(), parent_2.get_dna().len());
     }
 
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
 
     //Creation of the children DNA
     let mut dna_child_1 = vec![U::new_gene(); parent_1.get_dna().len()];
     let mut dna_child_2 = vec![U::new_gene(); parent_2.get_dna().len()];
     debug!(target="crossover_events", method="uniform"; "Starting the  uniform crossover");
 
-    let mut child_1 = U::new();
-    let mut child_2 = U::new();
+    //Children start from their parents so that they keep the rest of the genotype attributes
+    let mut child_1 = parent_1.clone();
+    let mut child_2 = parent_2.clone();
 
     for i in 0..parent_1.get_dna().len() {
         let crossover = rng.gen_range(0..2);
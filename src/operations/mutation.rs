@@ -1,27 +1,91 @@
+use crate::configuration::{MutationConfiguration, LimitConfiguration, ProblemSolving};
+use crate::helpers::{fitness_score, aga_ratio};
 use crate::traits::GenotypeT;
+use crate::domain::{Domain, locus_domain};
 use self::swap::swap;
 use self::inversion::inversion;
 use self::scramble::scramble;
+use self::gaussian::gaussian;
+use self::creep::creep;
+use self::self_adaptive::log_normal;
+use self::random_resetting::random_resetting;
 use super::Mutation;
 
 pub mod swap;
 pub mod inversion;
 pub mod scramble;
+pub mod gaussian;
+pub mod creep;
+pub mod self_adaptive;
+pub mod random_resetting;
 
 pub fn factory<U>(mutation: Mutation ,individual: &mut U)
 where
 U: GenotypeT + 'static
 {
-    match mutation {
+    factory_with_configuration(individual, MutationConfiguration{method: mutation, ..Default::default()})
+}
+
+/**
+ * Mutation factory with the configuration of the mutation, for the step size of the gaussian and creep mutations
+ */
+pub fn factory_with_configuration<U>(individual: &mut U, configuration: MutationConfiguration)
+where
+U: GenotypeT + 'static
+{
+    factory_with_domains(individual, configuration, &[])
+}
+
+/**
+ * Mutation factory with the domains of the loci: one domain for every locus or one per locus.
+ * The values changed by the gaussian and creep mutations are brought back to their domain.
+ */
+pub fn factory_with_domains<U>(individual: &mut U, configuration: MutationConfiguration, domains: &[Domain<U::Gene>])
+where
+U: GenotypeT + 'static
+{
+    let step_size = configuration.step_size.unwrap_or(1.0);
+    match configuration.method {
         Mutation::Swap => {swap(individual)},
         Mutation::Inversion => {inversion(individual)},
         Mutation::Scramble => {scramble(individual)},
+        Mutation::Gaussian => {
+            //Self-adaptive genotypes mutate their strategy parameters before the genes
+            if !individual.get_strategy_parameters().is_empty() {
+                log_normal(individual);
+            }
+            gaussian(individual, step_size)
+        },
+        Mutation::Creep => {
+            if !individual.get_strategy_parameters().is_empty() {
+                log_normal(individual);
+            }
+            creep(individual, step_size)
+        },
+        Mutation::RandomResetting => {random_resetting(individual, domains)},
     }
+
+    if !domains.is_empty() && matches!(configuration.method, Mutation::Gaussian | Mutation::Creep) {
+        let mut dna = individual.get_dna().to_vec();
+        for (locus, gene) in dna.iter_mut().enumerate() {
+            locus_domain(domains, locus).repair(gene);
+        }
+        individual.set_dna(&dna);
+    }
+
+    //The fitness of the mutated individual is not valid until it's calculated
+    individual.set_fitness_valid(false);
 }
 
-//Function to calculate the probability for adaptive genetic algorithms
+//Function to calculate the probability for adaptive genetic algorithms, over the fitness as in a maximization problem
 pub fn aga_probability<U: GenotypeT>(parent_1: &U, parent_2: &U, f_avg: f64, probability_max: f64, probability_min: f64)->f64{
-    let larger_f = if parent_1.get_fitness() > parent_2.get_fitness() {parent_1.get_fitness()}else{parent_2.get_fitness()};
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+    aga_probability_with_limit_configuration(parent_1, parent_2, f_avg, probability_max, probability_min, &maximization)
+}
+
+//Function to calculate the probability for adaptive genetic algorithms, over the scores of the problem of the limit configuration
+pub fn aga_probability_with_limit_configuration<U: GenotypeT>(parent_1: &U, parent_2: &U, f_avg: f64, probability_max: f64, probability_min: f64, limit_configuration: &LimitConfiguration)->f64{
+    let larger_f = fitness_score(parent_1, limit_configuration).max(fitness_score(parent_2, limit_configuration));
 
     if larger_f >= f_avg {
         probability_min
@@ -29,4 +93,30 @@ pub fn aga_probability<U: GenotypeT>(parent_1: &U, parent_2: &U, f_avg: f64, pro
         probability_max
     }
 
-}
\ No newline at end of file
+}
+
+//Function to calculate the probability for the Srinivas-Patnaik adaptive genetic algorithms, with k2 = k4 = probability_max
+pub fn srinivas_patnaik_probability<U: GenotypeT>(parent_1: &U, parent_2: &U, f_max: f64, f_avg: f64, probability_max: f64, probability_min: f64, limit_configuration: &LimitConfiguration)->f64{
+    let larger_f = fitness_score(parent_1, limit_configuration).max(fitness_score(parent_2, limit_configuration));
+
+    if larger_f >= f_avg {
+        (probability_max * aga_ratio(larger_f, f_max, f_avg)).max(probability_min)
+    }else{
+        probability_max
+    }
+}
+
+/**
+ * Function to get the step size of each gene: the strategy parameters of self-adaptive genotypes, or the given step size otherwise
+ */
+pub fn step_sizes<U: GenotypeT>(individual: &U, step_size: f64) -> Vec<f64>{
+    let dna_len = individual.get_dna().len();
+    let strategy_parameters = individual.get_strategy_parameters();
+
+    match strategy_parameters.len() {
+        0 => vec![step_size; dna_len],
+        1 => vec![strategy_parameters[0]; dna_len],
+        len if len == dna_len => strategy_parameters.to_vec(),
+        len => panic!("The strategy parameters must have one step size or one step size per gene. The individual has {} genes and {} strategy parameters.", dna_len, len),
+    }
+}
@@ -0,0 +1,22 @@
+use rand::Rng;
+use crate::traits::{GenotypeT, GeneT};
+use super::step_sizes;
+use log::{trace, debug};
+
+pub fn creep<U: GenotypeT>(individual: &mut U, step_size: f64){
+
+    //Every gene is perturbed with a uniform value between -step size and +step size
+    debug!(target="mutation_events", method="creep"; "Starting the creep mutation");
+    let mut rng = crate::rng::rng();
+    let step_sizes = step_sizes(individual, step_size);
+
+    let mut dna = individual.get_dna().to_vec();
+    for (gene, step_size) in dna.iter_mut().zip(step_sizes){
+        let value = gene.get_value() + rng.gen_range(-1.0..=1.0) * step_size;
+        trace!(target="mutation_events", method="creep"; "Gene value {} - new value {}", gene.get_value(), value);
+        gene.set_value(value);
+    }
+
+    individual.set_dna(&dna);
+    debug!(target="mutation_events", method="creep"; "Creep mutation finished");
+}
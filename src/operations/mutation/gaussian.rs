@@ -0,0 +1,22 @@
+use crate::traits::{GenotypeT, GeneT};
+use crate::helpers::standard_normal;
+use super::step_sizes;
+use log::{trace, debug};
+
+pub fn gaussian<U: GenotypeT>(individual: &mut U, step_size: f64){
+
+    //Every gene is perturbed with a normal distribution with its own step size
+    debug!(target="mutation_events", method="gaussian"; "Starting the gaussian mutation");
+    let mut rng = crate::rng::rng();
+    let step_sizes = step_sizes(individual, step_size);
+
+    let mut dna = individual.get_dna().to_vec();
+    for (gene, step_size) in dna.iter_mut().zip(step_sizes){
+        let value = gene.get_value() + step_size * standard_normal(&mut rng);
+        trace!(target="mutation_events", method="gaussian"; "Gene value {} - new value {}", gene.get_value(), value);
+        gene.set_value(value);
+    }
+
+    individual.set_dna(&dna);
+    debug!(target="mutation_events", method="gaussian"; "Gaussian mutation finished");
+}
@@ -5,7 +5,7 @@ use log::{trace, debug};
 pub fn inversion<U: GenotypeT>(individual: &mut U) {
     // Starting the inversion mutation and obtaining two random indices
     debug!(target="mutation_events", method="inversion"; "Starting the inversion mutation");
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     let len = individual.get_dna().len();
     
     // Select two distinct random indices
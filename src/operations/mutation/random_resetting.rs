@@ -0,0 +1,24 @@
+use rand::Rng;
+use crate::{traits::{GenotypeT, GeneT}, domain::{Domain, locus_domain}};
+use log::{trace, debug};
+
+pub fn random_resetting<U: GenotypeT>(individual: &mut U, domains: &[Domain<U::Gene>]){
+
+    //A random gene is replaced with a random value of the domain of its locus
+    debug!(target="mutation_events", method="random_resetting"; "Starting the random resetting mutation");
+    if domains.is_empty() {
+        panic!("The random resetting mutation needs the domains of the loci.");
+    }
+    let dna_len = individual.get_dna().len();
+    if dna_len == 0 {
+        return;
+    }
+
+    let mut rng = crate::rng::rng();
+    let locus = rng.gen_range(0..dna_len);
+    let gene = locus_domain(domains, locus).sample(&mut rng);
+    trace!(target="mutation_events", method="random_resetting"; "Locus {} - old value {} - new value {}", locus, individual.get_dna()[locus].get_value(), gene.get_value());
+    individual.set_gene(locus, gene);
+
+    debug!(target="mutation_events", method="random_resetting"; "Random resetting mutation finished");
+}
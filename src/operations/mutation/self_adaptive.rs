@@ -0,0 +1,39 @@
+use crate::traits::GenotypeT;
+use crate::helpers::standard_normal;
+use log::{trace, debug};
+
+//Lower bound of the step sizes, to avoid the search from stagnating
+pub const MIN_STEP_SIZE: f64 = 1e-10;
+
+/**
+ * Log-normal self-adaptation of the strategy parameters of an individual.
+ * With a single step size: sigma' = sigma * exp(tau_0 * N(0,1)), with tau_0 = 1 / sqrt(n).
+ * With a step size per gene: sigma_i' = sigma_i * exp(tau' * N(0,1) + tau * N_i(0,1)), with tau' = 1 / sqrt(2n) and tau = 1 / sqrt(2 * sqrt(n)).
+ */
+pub fn log_normal<U: GenotypeT>(individual: &mut U){
+
+    debug!(target="mutation_events", method="log_normal"; "Starting the log-normal self-adaptation");
+    let mut rng = crate::rng::rng();
+    let n = individual.get_dna().len().max(1) as f64;
+    let mut strategy_parameters = individual.get_strategy_parameters().to_vec();
+
+    if strategy_parameters.len() == 1 {
+        let tau_0 = 1.0 / n.sqrt();
+        strategy_parameters[0] *= (tau_0 * standard_normal(&mut rng)).exp();
+    }else{
+        let tau_prime = 1.0 / (2.0 * n).sqrt();
+        let tau = 1.0 / (2.0 * n.sqrt()).sqrt();
+        let common = tau_prime * standard_normal(&mut rng);
+        for step_size in strategy_parameters.iter_mut(){
+            *step_size *= (common + tau * standard_normal(&mut rng)).exp();
+        }
+    }
+
+    for step_size in strategy_parameters.iter_mut(){
+        *step_size = step_size.max(MIN_STEP_SIZE);
+    }
+    trace!(target="mutation_events", method="log_normal"; "New strategy parameters {:?}", strategy_parameters);
+
+    individual.set_strategy_parameters(&strategy_parameters);
+    debug!(target="mutation_events", method="log_normal"; "Log-normal self-adaptation finished");
+}
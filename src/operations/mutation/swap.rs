@@ -6,7 +6,7 @@ pub fn swap<U: GenotypeT>(individual: &mut U){
 
     //Getting two random genes from the dna of the individual
     debug!(target="mutation_events", method="swap"; "Starting the swap mutation");
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     let index_1 = rng.gen_range(0..individual.get_dna().len());
     let index_2 = rng.gen_range(0..individual.get_dna().len());
     trace!(target="mutation_events", method="swap"; "Mutation index 1: {}, mutation index 2: {}", index_1, index_2);
@@ -0,0 +1,18 @@
+pub(crate) use crate::configuration::{LimitConfiguration, NichingConfiguration};
+use crate::traits::GenotypeT;
+use self::sharing::fitness_sharing;
+use self::clearing::clearing;
+
+use super::Niching;
+pub mod sharing;
+pub mod clearing;
+
+/**
+ * Function to replace the fitness of the individuals by their niche fitness before the parent selection
+ */
+pub fn factory<U: GenotypeT>(niching: Niching, individuals: &mut [U], niching_configuration: &NichingConfiguration, limit_configuration: &LimitConfiguration){
+    match niching {
+        Niching::FitnessSharing => {fitness_sharing(individuals, niching_configuration.sigma_share, niching_configuration.alpha, limit_configuration)},
+        Niching::Clearing => {clearing(individuals, niching_configuration.sigma_share, niching_configuration.niche_capacity as usize, limit_configuration)},
+    }
+}
\ No newline at end of file
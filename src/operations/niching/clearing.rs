@@ -0,0 +1,43 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::LimitConfiguration};
+use crate::helpers::fitness_ordering;
+use log::{trace, debug};
+
+pub fn clearing<U:GenotypeT>(individuals: &mut [U], sigma_share: f64, niche_capacity: usize, limit_configuration: &LimitConfiguration)
+{
+    debug!(target="niching_events", method="clearing"; "Starting clearing");
+
+    //1- Indexes of the individuals from the best to the worst
+    let mut indexes: Vec<usize> = (0..individuals.len()).collect();
+    indexes.sort_by(|a, b| fitness_ordering(&individuals[*a], &individuals[*b], limit_configuration));
+    let worst_fitness = match indexes.last() {
+        Some(worst) => individuals[*worst].get_fitness(),
+        None => return,
+    };
+
+    //2- Each individual not cleared yet is the best one of its niche, and only the best individuals of the niche keep their fitness
+    let mut cleared = vec![false; individuals.len()];
+    for (position, i) in indexes.iter().enumerate() {
+        if cleared[*i] {
+            continue;
+        }
+        let mut winners = 1;
+        for j in &indexes[position + 1..] {
+            if !cleared[*j] && individuals[*i].get_distance(&individuals[*j]) < sigma_share {
+                if winners < niche_capacity {
+                    winners += 1;
+                } else {
+                    cleared[*j] = true;
+                }
+            }
+        }
+    }
+
+    //3- The cleared individuals get the worst fitness of the population
+    for (individual, cleared) in individuals.iter_mut().zip(cleared) {
+        if cleared {
+            trace!(target="niching_events", method="clearing"; "Clearing individual with fitness {}", individual.get_fitness());
+            individual.set_fitness(worst_fitness);
+        }
+    }
+    debug!(target="niching_events", method="clearing"; "Clearing finished");
+}
\ No newline at end of file
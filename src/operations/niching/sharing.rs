@@ -0,0 +1,34 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::{LimitConfiguration, ProblemSolving}};
+use log::{trace, debug};
+
+pub fn fitness_sharing<U:GenotypeT>(individuals: &mut [U], sigma_share: f64, alpha: f64, limit_configuration: &LimitConfiguration)
+{
+    debug!(target="niching_events", method="fitness_sharing"; "Starting fitness sharing");
+
+    //1- Niche count of each individual, from the sharing function of the distances to the rest of the individuals
+    let niche_counts: Vec<f64> = individuals.iter().map(|individual| {
+        individuals.iter().map(|other| {
+            let distance = individual.get_distance(other);
+            if distance < sigma_share {1.0 - (distance / sigma_share).powf(alpha)} else {0.0}
+        }).sum()
+    }).collect();
+
+    //2- The fitness is shared from the lowest fitness of the population, or from 0.0 if it's positive, so it gets worse whatever its sign
+    let offset = individuals.iter().map(|individual| individual.get_fitness()).fold(0.0, f64::min);
+
+    //3- The fitness gets worse the more crowded the niche is
+    for (individual, niche_count) in individuals.iter_mut().zip(niche_counts) {
+        trace!(target="niching_events", method="fitness_sharing"; "Fitness {} - niche count {}", individual.get_fitness(), niche_count);
+        let fitness = individual.get_fitness();
+        let shared_fitness = match limit_configuration.problem_solving {
+            ProblemSolving::Maximization => offset + (fitness - offset) / niche_count,
+            ProblemSolving::Minimization => offset + (fitness - offset) * niche_count,
+            ProblemSolving::FixedFitness => {
+                let fitness_target = limit_configuration.fitness_target.unwrap();
+                fitness_target + (fitness - fitness_target) * niche_count
+            },
+        };
+        individual.set_fitness(shared_fitness);
+    }
+    debug!(target="niching_events", method="fitness_sharing"; "Fitness sharing finished");
+}
\ No newline at end of file
@@ -0,0 +1,195 @@
+use rand::Rng;
+use log::{trace, debug};
+use crate::{configuration::{OperatorSelectionConfiguration, LimitConfiguration, ProblemSolving}, traits::GenotypeT};
+use self::probability_matching::probability_matching;
+use self::adaptive_pursuit::adaptive_pursuit;
+use self::upper_confidence_bound::upper_confidence_bound;
+use super::OperatorSelection;
+
+pub mod probability_matching;
+pub mod adaptive_pursuit;
+pub mod upper_confidence_bound;
+
+/**
+ * Record of one application of the operators of the portfolio over a couple of parents
+ */
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OperatorApplication{
+    //Index of the crossover operator applied, if the crossover was made
+    pub crossover: Option<usize>,
+    //Index of the mutation operator applied, if any child was mutated
+    pub mutation: Option<usize>,
+    //Fitness improvement of the best child over the best parent
+    pub reward: f64,
+}
+
+/**
+ * Usage of an operator of the portfolio within a generation
+ */
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorUsage<T>{
+    pub operator: T,
+    //Number of times the operator has been applied in the generation
+    pub applications: usize,
+    //Probability of selecting the operator for the next generation
+    pub probability: f64,
+}
+
+/**
+ * Credit assignment and selection state for a portfolio of operators
+ */
+#[derive(Clone, Debug)]
+pub struct OperatorSelector{
+    pub method: OperatorSelection,
+    //Estimated quality (normalized reward) of each operator
+    pub qualities: Vec<f64>,
+    //Selection probability of each operator
+    pub probabilities: Vec<f64>,
+    //Number of times each operator has been applied since the start of the run
+    pub applications: Vec<usize>,
+    //Number of times each operator has been selected since the last update, not yet counted in the applications
+    pub pending_applications: Vec<usize>,
+    pub probability_min: f64,
+    pub adaptation_rate: f64,
+    pub learning_rate: f64,
+    pub exploration: f64,
+}
+
+impl OperatorSelector{
+
+    /**
+     * Creates a new selector for a portfolio of the given number of operators, all of them with the same probability
+     */
+    pub fn new(number_of_operators: usize, method: OperatorSelection, configuration: &OperatorSelectionConfiguration) -> OperatorSelector{
+        OperatorSelector {
+            method,
+            qualities: vec![0.0; number_of_operators],
+            probabilities: vec![1.0 / number_of_operators as f64; number_of_operators],
+            applications: vec![0; number_of_operators],
+            pending_applications: vec![0; number_of_operators],
+            probability_min: configuration.probability_min,
+            adaptation_rate: configuration.adaptation_rate,
+            learning_rate: configuration.learning_rate,
+            exploration: configuration.exploration,
+        }
+    }
+
+    /**
+     * Selects the index of the next operator to apply. The selection is counted as a pending application, so the upper confidence bound
+     * explores the operators within a generation. The applications are only counted in `update`, with the rewards of the generation.
+     */
+    pub fn select(&mut self) -> usize{
+        let operator = match self.method {
+            OperatorSelection::ProbabilityMatching | OperatorSelection::AdaptivePursuit => {
+                //Roulette wheel over the operator probabilities
+                let mut rng = crate::rng::rng();
+                let mut pointer = rng.gen_range(0.0..1.0);
+                let mut selected = self.probabilities.len() - 1;
+                for (index, probability) in self.probabilities.iter().enumerate(){
+                    if pointer < *probability {
+                        selected = index;
+                        break;
+                    }
+                    pointer -= probability;
+                }
+                selected
+            },
+            OperatorSelection::UpperConfidenceBound => {
+                let applications: Vec<usize> = self.applications.iter().zip(&self.pending_applications).map(|(applications, pending)| applications + pending).collect();
+                upper_confidence_bound(&self.qualities, &applications, self.exploration)
+            },
+        };
+        self.pending_applications[operator] += 1;
+
+        trace!(target="operator_selection_events", method="select"; "Operator selected: {}", operator);
+        operator
+    }
+
+    /**
+     * Updates the qualities and probabilities of the operators with the rewards of a generation.
+     * Rewards are normalized by the largest reward of the generation, and the pending applications are cleared.
+     */
+    pub fn update(&mut self, rewards: &[(usize, f64)]){
+
+        debug!(target="operator_selection_events", method="update"; "Starting the operator selection update");
+        let max_reward = rewards.iter().fold(0.0, |max: f64, (_, reward)| max.max(*reward));
+        self.pending_applications.iter_mut().for_each(|pending| *pending = 0);
+
+        for (operator, reward) in rewards {
+            let reward = if max_reward > 0.0 {reward / max_reward} else {0.0};
+            self.applications[*operator] += 1;
+            self.qualities[*operator] += self.adaptation_rate * (reward - self.qualities[*operator]);
+        }
+
+        match self.method {
+            OperatorSelection::ProbabilityMatching => {
+                self.probabilities = probability_matching(&self.qualities, self.probability_min);
+            },
+            OperatorSelection::AdaptivePursuit => {
+                self.probabilities = adaptive_pursuit(&self.qualities, &self.probabilities, self.probability_min, self.learning_rate);
+            },
+            OperatorSelection::UpperConfidenceBound => {
+                //UCB is deterministic, the probabilities reflect the share of applications of each operator
+                let total: usize = self.applications.iter().sum();
+                if total > 0 {
+                    self.probabilities = self.applications.iter().map(|applications| *applications as f64 / total as f64).collect();
+                }
+            },
+        }
+        debug!(target="operator_selection_events", method="update"; "Operator selection update finished");
+    }
+
+    /**
+     * Returns the usage of each operator of the portfolio for the given rewards of a generation
+     */
+    pub fn usage<T: Copy>(&self, operators: &[T], rewards: &[(usize, f64)]) -> Vec<OperatorUsage<T>>{
+        operators.iter().enumerate().map(|(index, operator)| OperatorUsage {
+            operator: *operator,
+            applications: rewards.iter().filter(|(applied, _)| *applied == index).count(),
+            probability: self.probabilities[index],
+        }).collect()
+    }
+}
+
+/**
+ * Selectors of the crossover and mutation portfolios. A selector is only set when its portfolio is not empty.
+ */
+#[derive(Clone, Debug, Default)]
+pub struct OperatorSelectors{
+    pub crossover: Option<OperatorSelector>,
+    pub mutation: Option<OperatorSelector>,
+}
+
+impl OperatorSelectors{
+
+    /**
+     * Creates the selectors from the configuration, without selectors if the adaptive operator selection is disabled
+     */
+    pub fn new(configuration: &OperatorSelectionConfiguration) -> OperatorSelectors{
+        OperatorSelectors {
+            crossover: configuration.method
+                .filter(|_| !configuration.crossover_operators.is_empty())
+                .map(|method| OperatorSelector::new(configuration.crossover_operators.len(), method, configuration)),
+            mutation: configuration.method
+                .filter(|_| !configuration.mutation_operators.is_empty())
+                .map(|method| OperatorSelector::new(configuration.mutation_operators.len(), method, configuration)),
+        }
+    }
+}
+
+/**
+ * Function to calculate the fitness improvement of a child over the best of its parents.
+ * Returns 0.0 when the child does not improve its parents.
+ */
+pub fn improvement<U: GenotypeT>(parent_1: &U, parent_2: &U, child: &U, limit_configuration: &LimitConfiguration) -> f64{
+    let improvement = match limit_configuration.problem_solving {
+        ProblemSolving::Maximization => {child.get_fitness() - parent_1.get_fitness().max(parent_2.get_fitness())},
+        ProblemSolving::Minimization => {parent_1.get_fitness().min(parent_2.get_fitness()) - child.get_fitness()},
+        ProblemSolving::FixedFitness => {
+            let fitness_target = limit_configuration.fitness_target.unwrap();
+            parent_1.get_fitness_distance(&fitness_target).min(parent_2.get_fitness_distance(&fitness_target)) - child.get_fitness_distance(&fitness_target)
+        },
+    };
+    improvement.max(0.0)
+}
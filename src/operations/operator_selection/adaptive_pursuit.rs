@@ -0,0 +1,23 @@
+/**
+ * Adaptive pursuit: the probability of the best operator is pushed towards the maximum probability,
+ * and the probabilities of the rest towards the minimum one
+ */
+pub fn adaptive_pursuit(qualities: &[f64], probabilities: &[f64], probability_min: f64, learning_rate: f64) -> Vec<f64>{
+    let probability_max = 1.0 - (qualities.len() as f64 - 1.0) * probability_min;
+
+    //Gets the best operator, the first one on ties
+    let mut best_operator = 0;
+    for (index, quality) in qualities.iter().enumerate(){
+        if *quality > qualities[best_operator] {
+            best_operator = index;
+        }
+    }
+
+    probabilities.iter().enumerate().map(|(index, probability)| {
+        if index == best_operator {
+            probability + learning_rate * (probability_max - probability)
+        }else{
+            probability + learning_rate * (probability_min - probability)
+        }
+    }).collect()
+}
@@ -0,0 +1,17 @@
+/**
+ * Probability matching: the probability of each operator is proportional to its quality,
+ * keeping a minimum probability for every operator
+ */
+pub fn probability_matching(qualities: &[f64], probability_min: f64) -> Vec<f64>{
+    let number_of_operators = qualities.len() as f64;
+    let total_quality: f64 = qualities.iter().sum();
+
+    //Without any quality, all the operators have the same probability
+    if total_quality <= 0.0 {
+        return vec![1.0 / number_of_operators; qualities.len()];
+    }
+
+    qualities.iter()
+        .map(|quality| probability_min + (1.0 - number_of_operators * probability_min) * (quality / total_quality))
+        .collect()
+}
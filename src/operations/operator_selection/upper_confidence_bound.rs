@@ -0,0 +1,24 @@
+/**
+ * Upper confidence bound: selects the operator with the best quality plus exploration bonus.
+ * Operators that have never been applied are selected first.
+ */
+pub fn upper_confidence_bound(qualities: &[f64], applications: &[usize], exploration: f64) -> usize{
+
+    if let Some(operator) = applications.iter().position(|applications| *applications == 0) {
+        return operator;
+    }
+
+    let total_applications = applications.iter().sum::<usize>() as f64;
+    let mut best_operator = 0;
+    let mut best_bound = f64::MIN;
+
+    for (index, quality) in qualities.iter().enumerate(){
+        let bound = quality + exploration * (2.0 * total_applications.ln() / applications[index] as f64).sqrt();
+        if bound > best_bound {
+            best_bound = bound;
+            best_operator = index;
+        }
+    }
+
+    best_operator
+}
@@ -0,0 +1,24 @@
+pub(crate) use crate::configuration::LimitConfiguration;
+use crate::traits::GenotypeT;
+use self::worst::worst;
+use self::random::random;
+use self::oldest::oldest;
+use self::parent_if_better::parent_if_better;
+
+use super::Replacement;
+pub mod worst;
+pub mod random;
+pub mod oldest;
+pub mod parent_if_better;
+
+/**
+ * Function to insert a child in the population replacing one of its individuals, depending on the replacement method
+ */
+pub fn factory<U: GenotypeT>(replacement: Replacement, individuals: &mut Vec<U>, child: U, parents: (usize, usize), limit_configuration: LimitConfiguration){
+    match replacement {
+        Replacement::Worst => {worst(individuals, child, limit_configuration)},
+        Replacement::Random => {random(individuals, child)},
+        Replacement::Oldest => {oldest(individuals, child)},
+        Replacement::ParentIfBetter => {parent_if_better(individuals, child, parents, limit_configuration)},
+    }
+}
\ No newline at end of file
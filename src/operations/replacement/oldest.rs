@@ -0,0 +1,17 @@
+pub(crate) use crate::traits::GenotypeT;
+use log::{trace, debug};
+
+pub fn oldest<U:GenotypeT>(individuals: &mut Vec<U>, child: U)
+{
+    debug!(target="replacement_events", method="oldest"; "Starting oldest replacement method");
+
+    //The age is the generation in which the individual was born, so the oldest one has the lowest age
+    match individuals.iter().enumerate().min_by_key(|(_, individual)| individual.get_age()).map(|(index, _)| index) {
+        Some(index) => {
+            trace!(target="replacement_events", method="oldest"; "Replacing the individual {}", index);
+            individuals[index] = child;
+        },
+        None => individuals.push(child),
+    }
+    debug!(target="replacement_events", method="oldest"; "Oldest replacement method finished");
+}
@@ -0,0 +1,19 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::LimitConfiguration};
+use crate::helpers::fitness_ordering;
+use std::cmp::Ordering;
+use log::{trace, debug};
+
+pub fn parent_if_better<U:GenotypeT>(individuals: &mut [U], child: U, parents: (usize, usize), limit_configuration: LimitConfiguration)
+{
+    debug!(target="replacement_events", method="parent_if_better"; "Starting parent if better replacement method");
+
+    //1- We get the worst of both parents
+    let worst_parent = if fitness_ordering(&individuals[parents.0], &individuals[parents.1], &limit_configuration) == Ordering::Less {parents.1} else {parents.0};
+
+    //2- The child only replaces the parent if it is better, otherwise it is discarded
+    if fitness_ordering(&child, &individuals[worst_parent], &limit_configuration) == Ordering::Less {
+        trace!(target="replacement_events", method="parent_if_better"; "Replacing the parent {}", worst_parent);
+        individuals[worst_parent] = child;
+    }
+    debug!(target="replacement_events", method="parent_if_better"; "Parent if better replacement method finished");
+}
@@ -0,0 +1,17 @@
+pub(crate) use crate::traits::GenotypeT;
+use rand::Rng;
+use log::{trace, debug};
+
+pub fn random<U:GenotypeT>(individuals: &mut Vec<U>, child: U)
+{
+    debug!(target="replacement_events", method="random"; "Starting random replacement method");
+    if individuals.is_empty() {
+        individuals.push(child);
+    } else {
+        //The child replaces an individual picked at random
+        let index = crate::rng::rng().gen_range(0..individuals.len());
+        trace!(target="replacement_events", method="random"; "Replacing the individual {}", index);
+        individuals[index] = child;
+    }
+    debug!(target="replacement_events", method="random"; "Random replacement method finished");
+}
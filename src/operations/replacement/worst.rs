@@ -0,0 +1,26 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::LimitConfiguration};
+use crate::helpers::fitness_ordering;
+use std::cmp::Ordering;
+use log::{trace, debug};
+
+pub fn worst<U:GenotypeT>(individuals: &mut Vec<U>, child: U, limit_configuration: LimitConfiguration)
+{
+    debug!(target="replacement_events", method="worst"; "Starting worst replacement method");
+
+    //1- We look for the worst individual of the population
+    let mut worst_index = 0;
+    for i in 1..individuals.len() {
+        if fitness_ordering(&individuals[i], &individuals[worst_index], &limit_configuration) == Ordering::Greater {
+            worst_index = i;
+        }
+    }
+
+    //2- The child replaces the worst individual
+    trace!(target="replacement_events", method="worst"; "Replacing the individual {}", worst_index);
+    if individuals.is_empty() {
+        individuals.push(child);
+    } else {
+        individuals[worst_index] = child;
+    }
+    debug!(target="replacement_events", method="worst"; "Worst replacement method finished");
+}
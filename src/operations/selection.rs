@@ -1,12 +1,13 @@
 pub(crate) use std::collections::HashMap;
 
 use crate::configuration::SelectionConfiguration;
+use crate::parallel::WorkerPool;
 use crate::traits::GenotypeT;
 
 use self::random::random;
 use self::fitness_proportionate::roulette_wheel_selection;
 use self::fitness_proportionate::stochastic_universal_sampling;
-use self::tournament::tournament;
+use self::tournament::tournament_with_pool;
 
 use super::Selection;
 
@@ -17,11 +18,21 @@ pub mod tournament;
 pub fn factory<U>(individuals: &Vec<U>, configuration: SelectionConfiguration, number_of_threads: i32) -> HashMap<usize, usize>
 where
 U: GenotypeT + Sync + Send + 'static + Clone
+{
+    factory_with_pool(individuals, configuration, &WorkerPool::new(number_of_threads.max(1) as usize))
+}
+
+/**
+ * Selection of the couples, using the workers of the given pool in the methods that run in several threads
+ */
+pub fn factory_with_pool<U>(individuals: &Vec<U>, configuration: SelectionConfiguration, pool: &WorkerPool) -> HashMap<usize, usize>
+where
+U: GenotypeT + Sync + Send + 'static + Clone
 {
     match configuration.method {
         Selection::Random => {random(individuals)},
         Selection::RouletteWheel => {roulette_wheel_selection(individuals)},
         Selection::StochasticUniversalSampling => {stochastic_universal_sampling(individuals, configuration.number_of_couples)},
-        Selection::Tournament => {tournament(individuals, configuration.number_of_couples, number_of_threads)},
+        Selection::Tournament => {tournament_with_pool(individuals, configuration.number_of_couples, pool)},
     }
 }
\ No newline at end of file
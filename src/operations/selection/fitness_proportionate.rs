@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use rand::Rng;
 use log::{trace, debug};
 
+#[allow(clippy::ptr_arg)]
 pub fn roulette_wheel_selection<U:GenotypeT>(individuals: &Vec<U>) -> HashMap<usize, usize>{
 
     let mut mating = HashMap::new();
@@ -11,7 +12,7 @@ pub fn roulette_wheel_selection<U:GenotypeT>(individuals: &Vec<U>) -> HashMap<us
     debug!(target="selection_events", method="roulette_wheel_selection"; "Starting the roulette wheel selection");
     let total_fitness: f64 = individuals.iter().map(|ind| ind.get_fitness()).sum();
 
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
 
     trace!(target="selection_events", method="roulette_wheel_selection"; "Total fitness: {}", total_fitness);
 
@@ -50,7 +51,7 @@ pub fn stochastic_universal_sampling<U:GenotypeT>(individuals: &Vec<U>, couples:
     let total: f64 = individuals.iter().map(|gen| gen.get_fitness()).sum();
     let mut last_selection_value = 0.0;
     let mut selection_probabilities = Vec::new();
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
 
     trace!(target="selection_events", method="stochastic_universal_sampling"; "Total fitness: {}", total);
     for genotype in individuals{
@@ -67,13 +68,13 @@ pub fn stochastic_universal_sampling<U:GenotypeT>(individuals: &Vec<U>, couples:
 
     //3- Parent identification
     let mut current_point = starting_point;
-    let mut next_individual = 1;
 
     let mut end_of_individuals = false;
     let mut couple_completed = false;
     let mut first_mate = 0;
 
     for i in 0..individual_couples{
+        let next_individual = i + 1;
        
         //We check that there are enough individuals
         if i >= individuals.len(){
@@ -103,8 +104,6 @@ pub fn stochastic_universal_sampling<U:GenotypeT>(individuals: &Vec<U>, couples:
                 first_mate = i;
             }
         }
-
-        next_individual += 1;
     }
 
     debug!(target="mutation_events", method="stochastic_universal_sampling"; "Stochastic universal sampling finished");
@@ -6,11 +6,12 @@ use log::{trace, debug};
 /**
  * Function to make the random parent selection between the list of individuals
  */
+#[allow(clippy::ptr_arg)]
 pub fn random<U:GenotypeT>(individuals: &Vec<U>) -> HashMap<usize, usize>{
 
     let mut mating = HashMap::new();
     let mut indexes = Vec::new();
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     debug!(target="selection_events", method="random"; "Starting random selection");
 
     //Setting the indexes of the individuals
@@ -1,7 +1,6 @@
 use crate::traits::GenotypeT;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::{sync::Mutex, thread};
+use crate::parallel::{WorkerPool, chunk_ranges};
 use rand::Rng;
 use log::{trace, debug};
 
@@ -12,13 +11,23 @@ pub fn tournament<U>(individuals: &Vec<U>, couples: i32, number_of_threads: i32)
 where
 U:GenotypeT + Send + Sync + 'static + Clone
 {
-    
+    tournament_with_pool(individuals, couples, &WorkerPool::new(number_of_threads.max(1) as usize))
+}
+
+/**
+ * Tournament selection in the workers of the given pool
+ */
+pub fn tournament_with_pool<U>(individuals: &Vec<U>, couples: i32, pool: &WorkerPool) -> HashMap<usize, usize>
+where
+U:GenotypeT + Send + Sync + 'static + Clone
+{
+    let number_of_threads = pool.threads() as i32;
     if number_of_threads == 1{
         tournament_single_thread(individuals, couples)
     }else{
         let number_of_threads_t = if number_of_threads > couples {couples}else{number_of_threads};
         let number_of_threads_t = if number_of_threads_t & 1 == 1 {number_of_threads_t-1}else{number_of_threads_t};
-        tournament_multithread(individuals, couples, number_of_threads_t)
+        tournament_multithread(individuals, couples, number_of_threads_t, pool)
     }
 }
 
@@ -26,13 +35,14 @@ U:GenotypeT + Send + Sync + 'static + Clone
 /**
  * Function for tournament selection in a single thread 
  */
+#[allow(clippy::ptr_arg)]
 fn tournament_single_thread<U>(individuals: &Vec<U>, couples: i32) -> HashMap<usize, usize>
 where
 U:GenotypeT
 {
 
     debug!(target="selection_events", method="tournament"; "Starting tournament selection in single thread");
-    let mut rng = rand::thread_rng();
+    let mut rng = crate::rng::rng();
     let mut mating = HashMap::new();
     let individual_couples = couples*2;
 
@@ -86,7 +96,8 @@ U:GenotypeT
 /**
  * Function for tournament selection in multithread 
  */
-fn tournament_multithread<U>(individuals: &Vec<U>, couples: i32, number_of_threads: i32) -> HashMap<usize, usize>
+#[allow(clippy::ptr_arg)]
+fn tournament_multithread<U>(individuals: &Vec<U>, couples: i32, number_of_threads: i32, pool: &WorkerPool) -> HashMap<usize, usize>
 where
 U:GenotypeT+ Send + Sync + 'static + Clone
 {
@@ -96,83 +107,47 @@ U:GenotypeT+ Send + Sync + 'static + Clone
     let couples = if couples*2 > individuals.len() as i32 {(individuals.len() / 2) as i32}else{couples};
 
     //Sets the indexes
-    let mut indexes = Vec::new();
-    for i in 0..couples*2{
-        indexes.push(i);
-    }
-
-    //Thread control
-    let mut handles = vec![];
-
-    //Variables that will be sent
-    let left = Arc::new(Mutex::new(Vec::new()));
-    let right = Arc::new(Mutex::new(Vec::new()));
-    //let indexes = Arc::new(Mutex::new(indexes));
-    let mut start_index = 0;
-    let jump = indexes.len() as i32 / number_of_threads;
-    let individuals = Arc::new(Mutex::new(Vec::from_iter(individuals[..].iter().cloned())));
-    trace!(target="selection_events", method="tournament"; "start index: {}, jump: {}", start_index, jump);
-
-    //Running the different threads
-    for thread in 0..number_of_threads{
-        
-        //Copies of the variables
-        let winners = if thread & 1 == 1 {Arc::clone(&left)}else{Arc::clone(&right)};
-        let individuals = Arc::clone(&individuals);
-
-        let indexes_len = indexes.len();
-        let end_index = if start_index + jump > indexes_len as i32 {indexes_len as i32}else{start_index + jump};
-        let indexes = Vec::from_iter(indexes[start_index as usize..end_index as usize].iter().cloned());
-        let indexes = Arc::new(Mutex::new(indexes));
-
-        trace!(target="selection_events", method="tournament"; "Thread {} - indexes length {} - end index {}", thread, indexes_len, end_index);
-
-        //Run the thread
-        let handle = thread::spawn(move || {
-            
-            let mut rng = rand::thread_rng();
-            let individuals_t = individuals.lock().unwrap().clone();
-            let mut indexes_t = indexes.lock().unwrap().clone();
-
-            for _ in 0..indexes_t.len(){
-
-                //Gets the indexes for the tournament
-                let index_1 = rng.gen_range(0..indexes_t.len());
-                let final_index_1 = indexes_t[index_1];
-
-                let index_2 = rng.gen_range(0..indexes_t.len());
-                let final_index_2 = indexes_t[index_2];
-                trace!(target="selection_events", method="tournament"; "Thread {} - indexes 1 {} - final index 1 {} - index 2 {} - final index 2 {} ", thread, index_1, final_index_1, index_2, final_index_2);
-
-                //Compare both individuals
-                if individuals_t[final_index_1 as usize].get_fitness() >= individuals_t[final_index_2 as usize].get_fitness(){
-                    winners.lock().unwrap().push(final_index_1);
-                    indexes_t.remove(index_1);
-                }else{
-                    winners.lock().unwrap().push(final_index_2);
-                    indexes_t.remove(index_2);
-                }
+    let indexes: Vec<i32> = (0..couples*2).collect();
+
+    //Each worker makes the tournaments of its chunk of indexes, borrowing the individuals
+    let chunks: Vec<&[i32]> = chunk_ranges(indexes.len(), number_of_threads as usize).into_iter().map(|range| &indexes[range]).collect();
+    trace!(target="selection_events", method="tournament"; "Chunks of indexes: {}", chunks.len());
+
+    let winners = pool.map(chunks, |indexes| {
+        let mut rng = crate::rng::rng();
+        let mut indexes_t = indexes.to_vec();
+        let mut winners = Vec::with_capacity(indexes_t.len());
+
+        for _ in 0..indexes_t.len(){
+
+            //Gets the indexes for the tournament
+            let index_1 = rng.gen_range(0..indexes_t.len());
+            let final_index_1 = indexes_t[index_1];
+
+            let index_2 = rng.gen_range(0..indexes_t.len());
+            let final_index_2 = indexes_t[index_2];
+            trace!(target="selection_events", method="tournament"; "Indexes 1 {} - final index 1 {} - index 2 {} - final index 2 {} ", index_1, final_index_1, index_2, final_index_2);
+
+            //Compare both individuals
+            if individuals[final_index_1 as usize].get_fitness() >= individuals[final_index_2 as usize].get_fitness(){
+                winners.push(final_index_1);
+                indexes_t.remove(index_1);
+            }else{
+                winners.push(final_index_2);
+                indexes_t.remove(index_2);
             }
+        }
+        winners
+    });
 
-        });
-        handles.push(handle);
-
-        start_index = if start_index + jump > indexes_len as i32 {indexes_len as i32}else{start_index + jump};
-        trace!(target="selection_events", method="tournament"; "Thread {} - start index {}", thread, start_index);
-    }
-
-    for handle in handles{
-        handle.join().unwrap();
-    }
-
-    //Gets the final vectors
-    let left = left.lock().unwrap();
-    let right = right.lock().unwrap();
+    //The winners of the odd chunks are mated with the winners of the even chunks
+    let left: Vec<i32> = winners.iter().skip(1).step_by(2).flatten().copied().collect();
+    let right: Vec<i32> = winners.iter().step_by(2).flatten().copied().collect();
 
     //Inserts the keys and values into the hashmap
-    for item in 0..left.len() {
-        mating.insert(left[item] as usize, right[item] as usize);
-        trace!(target="selection_events", method="tournament"; "Mating index 1: {} - index 2: {} ", left[item], right[item]);
+    for (left, right) in left.iter().zip(right.iter()) {
+        mating.insert(*left as usize, *right as usize);
+        trace!(target="selection_events", method="tournament"; "Mating index 1: {} - index 2: {} ", left, right);
     }
 
     debug!(target="selection_events", method="tournament"; "Tournament selection in multiple threads finished");
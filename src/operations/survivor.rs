@@ -1,15 +1,47 @@
 pub(crate) use crate::configuration::LimitConfiguration;
+use crate::configuration::GaConfiguration;
 use crate::traits::GenotypeT;
 use self::fitness::fitness_based;
 use self::age::age_based;
+use self::crowding::deterministic_crowding;
+use self::restricted_tournament::restricted_tournament;
 
 use super::Survivor;
 pub mod fitness;
 pub mod age;
+pub mod crowding;
+pub mod restricted_tournament;
 
+/**
+ * Function to select the survivors from the population, once the offspring has been added to it.
+ * The niching survivors need the offspring apart from the population, so here they just keep the best individuals.
+ */
 pub fn factory<U: GenotypeT>(survivor: Survivor, individuals: &mut Vec<U>, population_size: usize, limit_configuration: LimitConfiguration){
     match survivor {
         Survivor::Fitness => {fitness_based(individuals, population_size, limit_configuration)},
         Survivor::Age => {age_based(individuals, population_size)},
+        Survivor::MuPlusLambda => {fitness_based(individuals, population_size, limit_configuration)},
+        Survivor::MuCommaLambda => {fitness_based(individuals, population_size, limit_configuration)},
+        Survivor::DeterministicCrowding => {fitness_based(individuals, population_size, limit_configuration)},
+        Survivor::RestrictedTournament => {fitness_based(individuals, population_size, limit_configuration)},
+    }
+}
+
+/**
+ * Function to know if a survivor method is a niching method, where each child competes with a similar individual
+ */
+pub fn is_niching(survivor: Survivor) -> bool {
+    survivor == Survivor::DeterministicCrowding || survivor == Survivor::RestrictedTournament
+}
+
+/**
+ * Function to insert the offspring in the population with a niching survivor method.
+ * The parents are the indexes in the population of the parents of each child.
+ */
+pub fn niching_factory<U: GenotypeT>(survivor: Survivor, individuals: &mut [U], offspring: Vec<U>, parents: &[(usize, usize)], configuration: &GaConfiguration){
+    match survivor {
+        Survivor::DeterministicCrowding => {deterministic_crowding(individuals, offspring, parents, &configuration.limit_configuration)},
+        Survivor::RestrictedTournament => {restricted_tournament(individuals, offspring, configuration.niching_configuration.window_size as usize, &configuration.limit_configuration)},
+        _ => panic!("The survivor method {:?} is not a niching method.", survivor),
     }
 }
\ No newline at end of file
@@ -0,0 +1,41 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::LimitConfiguration};
+use crate::helpers::fitness_ordering;
+use std::cmp::Ordering;
+use log::{trace, debug};
+
+pub fn deterministic_crowding<U:GenotypeT>(individuals: &mut [U], offspring: Vec<U>, parents: &[(usize, usize)], limit_configuration: &LimitConfiguration)
+{
+    debug!(target="survivor_events", method="deterministic_crowding"; "Starting deterministic crowding survivor method");
+
+    //The children of the same couple come together in the offspring
+    let mut offspring = offspring.into_iter().zip(parents.iter().copied()).peekable();
+    while let Some((child_1, (parent_1, parent_2))) = offspring.next() {
+        let child_2 = offspring.next_if(|(_, parents)| *parents == (parent_1, parent_2)).map(|(child, _)| child);
+
+        //1- Each child is paired with its most similar parent
+        let pairs = match child_2 {
+            Some(child_2) => {
+                let straight = individuals[parent_1].get_distance(&child_1) + individuals[parent_2].get_distance(&child_2);
+                let crossed = individuals[parent_1].get_distance(&child_2) + individuals[parent_2].get_distance(&child_1);
+                if straight <= crossed {
+                    vec![(child_1, parent_1), (child_2, parent_2)]
+                } else {
+                    vec![(child_1, parent_2), (child_2, parent_1)]
+                }
+            },
+            None => {
+                let parent = if individuals[parent_1].get_distance(&child_1) <= individuals[parent_2].get_distance(&child_1) {parent_1} else {parent_2};
+                vec![(child_1, parent)]
+            },
+        };
+
+        //2- The child replaces its parent unless the parent is better
+        for (child, parent) in pairs {
+            if fitness_ordering(&individuals[parent], &child, limit_configuration) != Ordering::Less {
+                trace!(target="survivor_events", method="deterministic_crowding"; "Replacing the parent {}", parent);
+                individuals[parent] = child;
+            }
+        }
+    }
+    debug!(target="survivor_events", method="deterministic_crowding"; "Deterministic crowding survivor method finished");
+}
\ No newline at end of file
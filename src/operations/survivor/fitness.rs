@@ -1,40 +1,22 @@
 pub(crate) use crate::{traits::GenotypeT, configuration::{ProblemSolving, LimitConfiguration}};
+use crate::helpers::fitness_ordering;
 use log::{trace, debug};
 
 pub fn fitness_based<U:GenotypeT>(individuals: &mut Vec<U>, population_size: usize, limit_configuration: LimitConfiguration)
 {
     debug!(target="survivor_events", method="fitness_based"; "Starting fitness based survivor method");
-    if limit_configuration.problem_solving != ProblemSolving::FixedFitness {
-        //We sort the individuals by their fitness if there is not a fixed fitness problem
-        individuals.sort_by(|a, b| b.get_fitness().partial_cmp(&a.get_fitness()).unwrap());
-    }else{
-        //We sort the individuals by their distance with the fitness target in a fixed fitness problem
-        individuals.sort_by(|a, b| b.get_fitness_distance(&limit_configuration.fitness_target.unwrap()).partial_cmp(&a.get_fitness_distance(&limit_configuration.fitness_target.unwrap())).unwrap());
-    }
+    let compare = |a: &U, b: &U| fitness_ordering(a, b, &limit_configuration);
 
-    //If there is more individuals than the defined population number
+    //If there is more individuals than the defined population number, we keep the best ones without sorting the rest
     trace!(target="survivor_events", method="fitness_based"; "Individuals length {} - population size {}", individuals.len(), population_size);
     if individuals.len() > population_size {
-        let individuals_to_remove = individuals.len() - population_size;
-
-        match limit_configuration.problem_solving {
-            ProblemSolving::Maximization => {
-                for _i in 0..individuals_to_remove{
-                    individuals.remove(individuals.len() - 1);
-                }
-            },
-            ProblemSolving::Minimization => {
-                for _i in 0..individuals_to_remove{
-                    individuals.remove(0);
-                }
-            },
-            ProblemSolving::FixedFitness => {
-                for _i in 0..individuals_to_remove{
-                    individuals.remove(0);
-                }
-            },
+        if population_size > 0 {
+            individuals.select_nth_unstable_by(population_size - 1, compare);
         }
+        individuals.truncate(population_size);
     }
 
+    //The survivors are sorted from the best to the worst
+    individuals.sort_by(compare);
     debug!(target="survivor_events", method="fitness_based"; "Fitness based survivor method finished");
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+pub(crate) use crate::{traits::GenotypeT, configuration::LimitConfiguration};
+use crate::helpers::fitness_ordering;
+use std::cmp::Ordering;
+use rand::seq::index::sample;
+use log::{trace, debug};
+
+pub fn restricted_tournament<U:GenotypeT>(individuals: &mut [U], offspring: Vec<U>, window_size: usize, limit_configuration: &LimitConfiguration)
+{
+    debug!(target="survivor_events", method="restricted_tournament"; "Starting restricted tournament survivor method");
+    let mut rng = crate::rng::rng();
+    let window_size = window_size.min(individuals.len());
+
+    for child in offspring {
+
+        //1- We look for the most similar individual within a random window of the population
+        let closest = sample(&mut rng, individuals.len(), window_size).into_iter()
+            .min_by(|a, b| individuals[*a].get_distance(&child).total_cmp(&individuals[*b].get_distance(&child)));
+
+        //2- The child replaces the most similar individual if it is better
+        if let Some(closest) = closest {
+            if fitness_ordering(&child, &individuals[closest], limit_configuration) == Ordering::Less {
+                trace!(target="survivor_events", method="restricted_tournament"; "Replacing the individual {}", closest);
+                individuals[closest] = child;
+            }
+        }
+    }
+    debug!(target="survivor_events", method="restricted_tournament"; "Restricted tournament survivor method finished");
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+use std::{ops::Range, panic::{self, AssertUnwindSafe}};
+use crate::traits::GenotypeT;
+#[cfg(feature = "rayon")]
+use std::{collections::HashMap, sync::{Arc, Mutex, OnceLock}};
+#[cfg(not(feature = "rayon"))]
+use std::{sync::Mutex, thread};
+
+/**
+ * Splits a number of elements in chunks for the given number of threads. There are never more chunks than elements,
+ * the sizes of the chunks differ at most in one element, with the remainder in the first chunks, and they add up to the number of elements.
+ */
+pub fn chunk_sizes(elements: usize, threads: usize) -> Vec<usize>{
+    let chunks = threads.clamp(1, elements.max(1));
+    let (size, remainder) = (elements / chunks, elements % chunks);
+    (0..chunks).map(|chunk| if chunk < remainder {size + 1} else {size}).filter(|size| *size > 0).collect()
+}
+
+/**
+ * Splits a number of elements in consecutive ranges of indexes for the given number of threads, with the sizes of `chunk_sizes`
+ */
+pub fn chunk_ranges(elements: usize, threads: usize) -> Vec<Range<usize>>{
+    let mut start = 0;
+    chunk_sizes(elements, threads).into_iter().map(|size| {
+        start += size;
+        start - size..start
+    }).collect()
+}
+
+/**
+ * Splits a slice in consecutive mutable chunks for the given number of threads, with the sizes of `chunk_sizes`
+ */
+pub fn chunks_mut<T>(elements: &mut [T], threads: usize) -> Vec<&mut [T]>{
+    let mut chunks = Vec::new();
+    let mut rest = elements;
+    for size in chunk_sizes(rest.len(), threads) {
+        let (chunk, tail) = rest.split_at_mut(size);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+/**
+ * Pool of workers of the genetic algorithms. The tasks borrow their data from the caller, so the population is neither cloned nor locked.
+ * The workers are scoped threads, spawned by each call and joined before it returns, so they never outlive the borrowed data.
+ * With the `rayon` feature, the workers are persistent threads, shared by every pool with the same number of threads.
+ */
+#[derive(Clone)]
+pub struct WorkerPool{
+    threads: usize,
+    #[cfg(feature = "rayon")]
+    pool: Arc<rayon::ThreadPool>,
+}
+
+impl WorkerPool{
+    /**
+     * Gets the pool with the given number of threads
+     */
+    pub fn new(threads: usize) -> Self{
+        let threads = threads.max(1);
+        WorkerPool {
+            threads,
+            #[cfg(feature = "rayon")]
+            pool: shared_pool(threads),
+        }
+    }
+
+    /**
+     * Number of threads of the pool
+     */
+    pub fn threads(&self) -> usize{
+        self.threads
+    }
+
+    /**
+     * Calculates the fitness of the individuals. Each worker owns a chunk of the individuals, so the evaluations run concurrently
+     */
+    pub fn evaluate<U>(&self, individuals: &mut [U])
+    where
+        U: GenotypeT + Send
+    {
+        self.map_owned_chunks(individuals, |chunk| chunk.iter_mut().for_each(|individual| individual.calculate_fitness()));
+    }
+
+    /**
+     * Moves the individuals to the workers in owned chunks, with the sizes of `chunk_sizes`, applies the function to each chunk and
+     * moves the individuals back to their positions. Returns the results of the chunks in order.
+     * If the function panics, every chunk is moved back before the panic is raised in the caller.
+     */
+    pub fn map_owned_chunks<U, R, F>(&self, individuals: &mut [U], f: F) -> Vec<R>
+    where
+        U: GenotypeT + Send,
+        R: Send,
+        F: Fn(&mut [U]) -> R + Sync
+    {
+        let sizes = chunk_sizes(individuals.len(), self.threads);
+        let mut owned = individuals.iter_mut().map(std::mem::take);
+        let chunks: Vec<Vec<U>> = sizes.into_iter().map(|size| owned.by_ref().take(size).collect()).collect();
+
+        let (chunks, results): (Vec<Vec<U>>, Vec<std::thread::Result<R>>) = self.map(chunks, |mut chunk| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(&mut chunk)));
+            (chunk, result)
+        }).into_iter().unzip();
+
+        for (position, individual) in individuals.iter_mut().zip(chunks.into_iter().flatten()) {
+            *position = individual;
+        }
+        results.into_iter().map(|result| result.unwrap_or_else(|error| panic::resume_unwind(error))).collect()
+    }
+
+    /**
+     * Applies the function to every input in the workers, returning the results in the order of the inputs.
+     * Each task is seeded from the current thread, so the seeded runs are reproducible. With a single thread or a single input,
+     * the function is applied in the current thread.
+     */
+    pub fn map<T, R, F>(&self, inputs: Vec<T>, f: F) -> Vec<R>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync
+    {
+        if self.threads == 1 || inputs.len() <= 1 {
+            return inputs.into_iter().map(f).collect();
+        }
+
+        let tasks: Vec<(Option<u64>, T)> = inputs.into_iter().map(|input| (crate::rng::spawn_seed(), input)).collect();
+        let task = |(seed, input): (Option<u64>, T)| {
+            crate::rng::set_seed(seed);
+            f(input)
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            self.pool.install(|| tasks.into_par_iter().map(task).collect())
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            //Each worker takes the next task until there are no more, keeping the results, or the panics, with the positions of the tasks
+            let number_of_tasks = tasks.len();
+            let queue = Mutex::new(tasks.into_iter().enumerate());
+            let (task, queue) = (&task, &queue);
+            let finished_tasks: Vec<(usize, thread::Result<R>)> = thread::scope(|scope| {
+                let workers: Vec<_> = (0..self.threads.min(number_of_tasks)).map(|index| {
+                    thread::Builder::new().name(format!("ga-worker-{}", index)).spawn_scoped(scope, move || {
+                        let mut finished_tasks = Vec::new();
+                        loop {
+                            //The lock is only held while taking the next task
+                            let next_task = queue.lock().unwrap().next();
+                            match next_task {
+                                Some((position, input)) => finished_tasks.push((position, panic::catch_unwind(AssertUnwindSafe(|| task(input))))),
+                                None => break finished_tasks,
+                            }
+                        }
+                    }).expect("The workers of the pool could not be created.")
+                }).collect();
+                workers.into_iter().flat_map(|worker| worker.join().unwrap_or_default()).collect()
+            });
+
+            let mut results: Vec<Option<R>> = (0..number_of_tasks).map(|_| None).collect();
+            for (position, result) in finished_tasks {
+                match result {
+                    Ok(result) => results[position] = Some(result),
+                    Err(error) => panic::resume_unwind(error),
+                }
+            }
+            results.into_iter().map(|result| result.expect("The workers of the pool stopped before finishing the tasks.")).collect()
+        }
+    }
+}
+
+/**
+ * Gets the persistent pool with the given number of threads, creating it the first time
+ */
+#[cfg(feature = "rayon")]
+fn shared_pool(threads: usize) -> Arc<rayon::ThreadPool>{
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<rayon::ThreadPool>>>> = OnceLock::new();
+    let mut pools = POOLS.get_or_init(|| Mutex::new(HashMap::new())).lock().unwrap();
+    Arc::clone(pools.entry(threads).or_insert_with(|| {
+        Arc::new(rayon::ThreadPoolBuilder::new().num_threads(threads).thread_name(|index| format!("ga-worker-{}", index)).build()
+            .expect("The pool of workers could not be created."))
+    }))
+}
@@ -1,5 +1,7 @@
-use crate::traits::GenotypeT;
+use std::collections::{HashMap, HashSet};
+use crate::{traits::{GenotypeT, GeneT}, operations::{Crossover, Mutation, operator_selection::OperatorUsage}, configuration::{Distance, LimitConfiguration, ProblemSolving}, helpers::fitness_score, distance, statistics::{Diversity, GenerationStatistics}};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Population<U>
 where
     U: GenotypeT,
@@ -10,11 +12,29 @@ where
     //The numbers of the generation of this population
     pub generation_numbers: Vec<i32>,
 
-    //Average fitness of the population
+    //Average score of the population for the adaptive genetic algorithms (see `helpers::fitness_score`)
     pub f_avg: f64,
 
-    //Population largest fitness value
+    //Score of the best individual of the population for the adaptive genetic algorithms, the higher the better in every problem
     pub f_max: f64,
+
+    //Number of individuals with a finite score, averaged in f_avg
+    pub scored_individuals: usize,
+
+    //Usage of the crossover operators in the last generation, with adaptive operator selection
+    pub crossover_usage: Vec<OperatorUsage<Crossover>>,
+
+    //Usage of the mutation operators in the last generation, with adaptive operator selection
+    pub mutation_usage: Vec<OperatorUsage<Mutation>>,
+
+    //Crossover probability of the last generation (the maximum probability for adaptive genetic algorithms)
+    pub crossover_probability: f64,
+
+    //Mutation probability of the last generation (the maximum probability for adaptive genetic algorithms)
+    pub mutation_probability: f64,
+
+    //Statistics of each generation of the run
+    pub statistics: Vec<GenerationStatistics>,
 }
 
 impl<U> Population<U>
@@ -23,61 +43,84 @@ where
 {
     // Creates a new empty `Population`
     pub fn new_empty() -> Population<U> {
-        Population { individuals: vec![], generation_numbers: vec![], f_avg: 0.0, f_max: 0.0 }
+        Population::new(vec![])
     }
 
     // Creates a new `Population` with the given individuals as members.
     pub fn new(individuals: Vec<U>) -> Population<U> {
-        Population { individuals, generation_numbers: vec![], f_avg: 0.0, f_max: 0.0}
+        Population { 
+            individuals, 
+            generation_numbers: vec![], 
+            f_avg: 0.0, 
+            f_max: 0.0, 
+            scored_individuals: 0,
+            crossover_usage: vec![], 
+            mutation_usage: vec![],
+            crossover_probability: 1.0, 
+            mutation_probability: 1.0,
+            statistics: vec![],
+        }
     }
 
-    // Function to calculate f_avg and f_max of the first population
-    pub fn aga_init(& mut self){
-        self.f_max = 0.0;
-        self.f_avg = 0.0;
-        for individual in self.individuals.as_slice(){
-            self.f_max = if individual.get_fitness() > self.f_max {individual.get_fitness()} else{self.f_max};
-            self.f_avg += individual.get_fitness();
+    // Function to calculate f_avg and f_max for the adaptive genetic algorithms, over the scores of the individuals,
+    // so f_max is the score of the best individual in every problem. The individuals with a NaN fitness are not taken into account.
+    pub fn aga_statistics(&mut self, limit_configuration: &LimitConfiguration){
+        let scores: Vec<f64> = self.individuals.iter().map(|individual| fitness_score(individual, limit_configuration)).filter(|score| score.is_finite()).collect();
+        self.scored_individuals = scores.len();
+        if scores.is_empty() {
+            self.f_max = 0.0;
+            self.f_avg = 0.0;
+        } else {
+            self.f_max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            self.f_avg = scores.iter().sum::<f64>() / scores.len() as f64;
         }
-        self.f_avg /= self.individuals.len() as f64;
     }
 
-    // Adds an individual with a generation number.
+    // Function to calculate f_avg and f_max of the first population, over the fitness as in a maximization problem
+    #[deprecated(note = "use `aga_statistics`, with the limit configuration of the problem")]
+    pub fn aga_init(&mut self){
+        self.aga_statistics(&maximization());
+    }
+
+    // Adds an individual with a generation number. With aga, f_max and f_avg are updated over the fitness as in a maximization problem.
     pub fn add_individual_gn(&mut self, individual: U, generation_number: i32, aga: bool){
-        
-        if aga {
-            //We calculate the f_max
-            self.f_max = if individual.get_fitness() > self.f_max {individual.get_fitness()} else{self.f_max};
-
-            //We recalculate the average fitness and add the individual
-            self.f_avg *= self.individuals.len() as f64;
-            self.f_avg += individual.get_fitness();
-        }
+        let limit_configuration = maximization();
+        self.add_scored_individual_gn(individual, generation_number, if aga {Some(&limit_configuration)} else {None});
+    }
 
+    // Adds an individual with a generation number. With the limit configuration of an adaptive genetic algorithm, f_max and f_avg are updated
+    // with the score of the individual, without going through the entire population.
+    pub fn add_scored_individual_gn(&mut self, individual: U, generation_number: i32, aga: Option<&LimitConfiguration>){
+        if let Some(limit_configuration) = aga {
+            self.add_score(fitness_score(&individual, limit_configuration));
+        }
         self.individuals.push(individual);
         self.generation_numbers.push(generation_number);
-
-        if aga {
-            self.f_avg /= self.individuals.len() as f64;
-        }
-
     }
 
-    //Function to add individuals in the list and recalculate the fitness without going through the entire population
+    //Function to add individuals in the list. With aga, f_max and f_avg are updated over the fitness as in a maximization problem.
     pub fn add_individuals(&mut self, individuals: &mut Vec<U>, aga: bool){
+        let limit_configuration = maximization();
+        self.add_scored_individuals(individuals, if aga {Some(&limit_configuration)} else {None});
+    }
 
-        if aga {
-            self.f_avg *= self.individuals.len() as f64;
+    //Function to add individuals in the list. With the limit configuration of an adaptive genetic algorithm, f_max and f_avg are updated
+    //with the scores of the new individuals, without going through the entire population.
+    pub fn add_scored_individuals(&mut self, individuals: &mut Vec<U>, aga: Option<&LimitConfiguration>){
+        if let Some(limit_configuration) = aga {
+            for individual in individuals.iter() {
+                self.add_score(fitness_score(individual, limit_configuration));
+            }
         }
-        let individuals_c = individuals.clone();
         self.individuals.append(individuals);
-        if aga {
-            for individual in individuals_c{
-                //We calculate the f_max and add the values to the f_avg
-                self.f_max = if individual.get_fitness() > self.f_max {individual.get_fitness()} else{self.f_max};
-                self.f_avg += individual.get_fitness();
-            }
-            self.f_avg /= self.individuals.len() as f64;
+    }
+
+    // Updates f_max and f_avg with the score of a new individual. The NaN scores are not taken into account.
+    fn add_score(&mut self, score: f64){
+        if score.is_finite() {
+            self.f_max = if self.scored_individuals == 0 {score} else {self.f_max.max(score)};
+            self.f_avg = (self.f_avg * self.scored_individuals as f64 + score) / (self.scored_individuals + 1) as f64;
+            self.scored_individuals += 1;
         }
     }
 
@@ -85,4 +128,64 @@ where
     pub fn size(&self) -> usize {
         self.individuals.len()
     }
+
+    // Returns the mean distance between every pair of individuals, with the given metric.
+    pub fn mean_pairwise_distance(&self, distance: Distance) -> f64 {
+        let mut total_distance = 0.0;
+        for i in 0..self.individuals.len() {
+            for j in i + 1..self.individuals.len() {
+                total_distance += distance::factory(distance, &self.individuals[i], &self.individuals[j]);
+            }
+        }
+        let pairs = self.individuals.len() * self.individuals.len().saturating_sub(1) / 2;
+        if pairs > 0 {total_distance / pairs as f64} else {0.0}
+    }
+
+    // Returns the Shannon entropy (in bits) of the genes of each locus.
+    pub fn locus_entropy(&self) -> Vec<f64> {
+        let loci = self.individuals.iter().map(|individual| individual.get_dna().len()).max().unwrap_or(0);
+        (0..loci).map(|locus| {
+            let mut counts = HashMap::new();
+            for gene in self.individuals.iter().filter_map(|individual| individual.get_dna().get(locus)) {
+                *counts.entry(gene_key(gene)).or_insert(0) += 1;
+            }
+            let total: usize = counts.values().sum();
+            counts.values().map(|count| {
+                let probability = *count as f64 / total as f64;
+                -probability * probability.log2()
+            }).sum()
+        }).collect()
+    }
+
+    // Returns the number of different genotypes in the population.
+    pub fn unique_genotypes(&self) -> usize {
+        self.individuals.iter()
+            .map(|individual| individual.get_dna().iter().map(gene_key).collect::<Vec<(i32, u64)>>())
+            .collect::<HashSet<Vec<(i32, u64)>>>()
+            .len()
+    }
+
+    // Returns the diversity indicators of the population, with the given metric for the distances.
+    pub fn diversity(&self, distance: Distance) -> Diversity {
+        let locus_entropy = self.locus_entropy();
+        Diversity {
+            mean_distance: self.mean_pairwise_distance(distance),
+            entropy: if locus_entropy.is_empty() {0.0} else {locus_entropy.iter().sum::<f64>() / locus_entropy.len() as f64},
+            unique_genotypes: self.unique_genotypes(),
+        }
+    }
+}
+
+/**
+ * Limit configuration of the old statistics, where the score is the fitness
+ */
+fn maximization() -> LimitConfiguration {
+    LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()}
+}
+
+/**
+ * Function to identify a gene by its id and its numeric value
+ */
+fn gene_key<T: GeneT>(gene: &T) -> (i32, u64) {
+    (gene.get_id(), gene.get_value().to_bits())
 }
\ No newline at end of file
@@ -0,0 +1,105 @@
+pub mod binary;
+pub mod nk;
+pub mod knapsack;
+pub mod queens;
+pub mod continuous;
+pub mod tsp;
+
+use crate::{traits::{GeneT, GenotypeT}, configuration::ProblemSolving};
+
+/**
+ * Benchmark problem with a known optimum
+ */
+pub trait Problem: GenotypeT {
+    //Whether the fitness of the problem is maximized or minimized
+    fn problem_solving() -> ProblemSolving;
+
+    //Fitness of the optimal solutions, for the length of the dna of the individual
+    fn optimum(&self) -> f64;
+
+    //Whether the fitness of the individual is the optimum one, up to a tolerance of 1e-6
+    fn is_optimal(&self) -> bool {
+        (self.get_fitness() - self.optimum()).abs() <= 1e-6 * self.optimum().abs().max(1.0)
+    }
+}
+
+/**
+ * Binary gene, where the id is the bit. Setting a numeric value rounds it to 0 or 1.
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryGene {
+    pub id: i32,
+}
+impl BinaryGene {
+    /**
+     * Alleles 0 and 1, to be repeated within the dna
+     */
+    pub fn alleles() -> Vec<BinaryGene> {
+        vec![BinaryGene { id: 0 }, BinaryGene { id: 1 }]
+    }
+}
+impl GeneT for BinaryGene {
+    fn get_id(&self) -> i32 {
+        self.id
+    }
+    fn set_id(&mut self, id: i32) -> &mut Self {
+        self.id = id;
+        self
+    }
+    fn set_value(&mut self, value: f64) -> &mut Self {
+        self.id = if value >= 0.5 {1} else {0};
+        self
+    }
+}
+
+/**
+ * Integer gene, where the id is the value
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntegerGene {
+    pub id: i32,
+}
+impl GeneT for IntegerGene {
+    fn get_id(&self) -> i32 {
+        self.id
+    }
+    fn set_id(&mut self, id: i32) -> &mut Self {
+        self.id = id;
+        self
+    }
+}
+
+/**
+ * Real gene, where the id is the locus
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RealGene {
+    pub id: i32,
+    pub value: f64,
+}
+impl GeneT for RealGene {
+    fn get_id(&self) -> i32 {
+        self.id
+    }
+    fn set_id(&mut self, id: i32) -> &mut Self {
+        self.id = id;
+        self
+    }
+    fn get_value(&self) -> f64 {
+        self.value
+    }
+    fn set_value(&mut self, value: f64) -> &mut Self {
+        self.value = value;
+        self
+    }
+}
+
+/**
+ * Function to get the bits of a binary dna
+ */
+pub(crate) fn bits(dna: &[BinaryGene]) -> impl Iterator<Item = bool> + '_ {
+    dna.iter().map(|gene| gene.id != 0)
+}
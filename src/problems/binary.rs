@@ -0,0 +1,144 @@
+use super::{BinaryGene, GenotypeT, Problem, ProblemSolving, bits};
+
+/**
+ * OneMax: the fitness is the number of ones. The optimum is the length of the dna.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OneMax {
+    pub dna: Vec<BinaryGene>,
+    pub fitness: f64,
+    pub age: i32,
+}
+impl GenotypeT for OneMax {
+    type Gene = BinaryGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        self.fitness = bits(&self.dna).filter(|bit| *bit).count() as f64;
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for OneMax {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Maximization
+    }
+    fn optimum(&self) -> f64 {
+        self.dna.len() as f64
+    }
+}
+
+/**
+ * LeadingOnes: the fitness is the number of consecutive ones at the start of the dna. The optimum is the length of the dna.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeadingOnes {
+    pub dna: Vec<BinaryGene>,
+    pub fitness: f64,
+    pub age: i32,
+}
+impl GenotypeT for LeadingOnes {
+    type Gene = BinaryGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        self.fitness = bits(&self.dna).take_while(|bit| *bit).count() as f64;
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for LeadingOnes {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Maximization
+    }
+    fn optimum(&self) -> f64 {
+        self.dna.len() as f64
+    }
+}
+
+/**
+ * Concatenated deceptive traps of K bits. A block with u ones is worth K if all its bits are ones, and K - 1 - u otherwise,
+ * so the blocks lead to all zeros. The last block is shorter if the length of the dna is not a multiple of K.
+ * The optimum, with all the bits set to one, is the length of the dna.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trap<const K: usize> {
+    pub dna: Vec<BinaryGene>,
+    pub fitness: f64,
+    pub age: i32,
+}
+impl<const K: usize> GenotypeT for Trap<K> {
+    type Gene = BinaryGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        self.fitness = self.dna.chunks(K.max(1)).map(|block| {
+            let ones = bits(block).filter(|bit| *bit).count();
+            if ones == block.len() {block.len()} else {block.len() - 1 - ones}
+        }).sum::<usize>() as f64;
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl<const K: usize> Problem for Trap<K> {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Maximization
+    }
+    fn optimum(&self) -> f64 {
+        self.dna.len() as f64
+    }
+}
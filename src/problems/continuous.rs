@@ -0,0 +1,182 @@
+use std::{f64::consts::PI, marker::PhantomData};
+use super::{RealGene, GenotypeT, Problem, ProblemSolving};
+
+/**
+ * Continuous function to be minimized, with the same domain in every dimension
+ */
+pub trait Function: Clone + Default + Send + Sync {
+    //Lower and upper bounds of every variable
+    fn bounds() -> (f64, f64);
+
+    //Value of every variable at the global minimum
+    fn optimum_position() -> f64;
+
+    //Value of the function at a point
+    fn evaluate(x: &[f64]) -> f64;
+}
+
+/**
+ * Sphere function, the sum of the squares. Domain [-5.12, 5.12], minimum 0 at the origin.
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere;
+impl Function for Sphere {
+    fn bounds() -> (f64, f64) {
+        (-5.12, 5.12)
+    }
+    fn optimum_position() -> f64 {
+        0.0
+    }
+    fn evaluate(x: &[f64]) -> f64 {
+        x.iter().map(|x| x * x).sum()
+    }
+}
+
+/**
+ * Rastrigin function, highly multimodal. Domain [-5.12, 5.12], minimum 0 at the origin.
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rastrigin;
+impl Function for Rastrigin {
+    fn bounds() -> (f64, f64) {
+        (-5.12, 5.12)
+    }
+    fn optimum_position() -> f64 {
+        0.0
+    }
+    fn evaluate(x: &[f64]) -> f64 {
+        10.0 * x.len() as f64 + x.iter().map(|x| x * x - 10.0 * (2.0 * PI * x).cos()).sum::<f64>()
+    }
+}
+
+/**
+ * Rosenbrock function, with a narrow curved valley. Domain [-2.048, 2.048], minimum 0 at (1, ..., 1).
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rosenbrock;
+impl Function for Rosenbrock {
+    fn bounds() -> (f64, f64) {
+        (-2.048, 2.048)
+    }
+    fn optimum_position() -> f64 {
+        1.0
+    }
+    fn evaluate(x: &[f64]) -> f64 {
+        x.windows(2).map(|pair| 100.0 * (pair[1] - pair[0] * pair[0]).powi(2) + (1.0 - pair[0]).powi(2)).sum()
+    }
+}
+
+/**
+ * Ackley function, nearly flat with a deep hole at the centre. Domain [-32.768, 32.768], minimum 0 at the origin.
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ackley;
+impl Function for Ackley {
+    fn bounds() -> (f64, f64) {
+        (-32.768, 32.768)
+    }
+    fn optimum_position() -> f64 {
+        0.0
+    }
+    fn evaluate(x: &[f64]) -> f64 {
+        if x.is_empty() {
+            return 0.0;
+        }
+        let n = x.len() as f64;
+        let squares = x.iter().map(|x| x * x).sum::<f64>() / n;
+        let cosines = x.iter().map(|x| (2.0 * PI * x).cos()).sum::<f64>() / n;
+        //The result is clamped, as the rounding errors give a tiny negative value at the optimum
+        (-20.0 * (-0.2 * squares.sqrt()).exp() - cosines.exp() + 20.0 + std::f64::consts::E).max(0.0)
+    }
+}
+
+/**
+ * Schwefel function, deceptive, with the global minimum far from the next best local minima.
+ * Domain [-500, 500], minimum 0 (up to 1e-4 per dimension) at (420.9687, ..., 420.9687).
+ */
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schwefel;
+impl Function for Schwefel {
+    fn bounds() -> (f64, f64) {
+        (-500.0, 500.0)
+    }
+    fn optimum_position() -> f64 {
+        420.968746
+    }
+    fn evaluate(x: &[f64]) -> f64 {
+        418.9828872724338 * x.len() as f64 - x.iter().map(|x| x * x.abs().sqrt().sin()).sum::<f64>()
+    }
+}
+
+/**
+ * Minimization of a continuous function, with a variable in the value of each gene. The values set are clamped to the domain of the function.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Continuous<F: Function> {
+    pub dna: Vec<RealGene>,
+    pub fitness: f64,
+    pub age: i32,
+    function: PhantomData<F>,
+}
+impl<F: Function> Continuous<F> {
+    /**
+     * Alleles evenly spaced over the domain of the function, to be repeated within the dna and with unique ids for the loci
+     */
+    pub fn alleles(resolution: usize) -> Vec<RealGene> {
+        let (lower, upper) = F::bounds();
+        let steps = resolution.max(2) - 1;
+        (0..=steps).map(|i| RealGene { id: 0, value: lower + (upper - lower) * i as f64 / steps as f64 }).collect()
+    }
+
+    /**
+     * Creates an individual at the optimum, with the given number of variables
+     */
+    pub fn optimal(dimensions: usize) -> Self {
+        let mut individual = Self::new();
+        individual.set_dna(&(0..dimensions as i32).map(|id| RealGene { id, value: F::optimum_position() }).collect::<Vec<RealGene>>());
+        individual
+    }
+}
+impl<F: Function> GenotypeT for Continuous<F> {
+    type Gene = RealGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        let (lower, upper) = F::bounds();
+        self.dna = dna.iter().map(|gene| RealGene { id: gene.id, value: gene.value.clamp(lower, upper) }).collect();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        let x: Vec<f64> = self.dna.iter().map(|gene| gene.value).collect();
+        self.fitness = F::evaluate(&x);
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl<F: Function> Problem for Continuous<F> {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Minimization
+    }
+    fn optimum(&self) -> f64 {
+        0.0
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+use super::{BinaryGene, GenotypeT, Problem, ProblemSolving, bits};
+
+/**
+ * Instance of the 0/1 knapsack problem
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnapsackInstance {
+    pub weights: Vec<u32>,
+    pub values: Vec<u32>,
+    pub capacity: u32,
+}
+
+impl KnapsackInstance {
+    /**
+     * Creates an instance from the weights and values of the items and the capacity of the knapsack
+     */
+    pub fn new(weights: Vec<u32>, values: Vec<u32>, capacity: u32) -> Self {
+        if weights.len() != values.len() {
+            panic!("The knapsack items must have the same number of weights and values.");
+        }
+        KnapsackInstance { weights, values, capacity }
+    }
+
+    /**
+     * Function to get the fitness of a selection of items: the total value if the selection fits in the knapsack,
+     * and the negative excess of weight otherwise, so the feasible selections are always better
+     */
+    pub fn evaluate(&self, selection: &[bool]) -> f64 {
+        let (weight, value) = selection.iter().zip(self.weights.iter().zip(self.values.iter()))
+            .filter(|(selected, _)| **selected)
+            .fold((0u64, 0u64), |(weight, value), (_, (w, v))| (weight + *w as u64, value + *v as u64));
+        if weight <= self.capacity as u64 {value as f64} else {self.capacity as f64 - weight as f64}
+    }
+
+    /**
+     * Function to get the optimal value, by dynamic programming over the capacity
+     */
+    pub fn optimum(&self) -> f64 {
+        let capacity = self.capacity as usize;
+        let mut best = vec![0u64; capacity + 1];
+        for (weight, value) in self.weights.iter().zip(self.values.iter()) {
+            let weight = *weight as usize;
+            for c in (weight..=capacity).rev() {
+                best[c] = best[c].max(best[c - weight] + *value as u64);
+            }
+        }
+        best[capacity] as f64
+    }
+}
+
+/**
+ * 0/1 knapsack: each bit selects an item of the instance shared between the individuals.
+ * The instance must be set before calculating the fitness, with a prototype individual for the random initialization.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Knapsack {
+    pub dna: Vec<BinaryGene>,
+    pub fitness: f64,
+    pub age: i32,
+    pub instance: Option<Arc<KnapsackInstance>>,
+}
+impl Knapsack {
+    /**
+     * Creates an empty individual of the instance, to be used as a prototype
+     */
+    pub fn with_instance(instance: Arc<KnapsackInstance>) -> Self {
+        Knapsack { instance: Some(instance), ..Default::default() }
+    }
+
+    fn instance(&self) -> &KnapsackInstance {
+        self.instance.as_deref().expect("The knapsack individual has no instance.")
+    }
+}
+impl GenotypeT for Knapsack {
+    type Gene = BinaryGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        let selection: Vec<bool> = bits(&self.dna).collect();
+        self.fitness = self.instance().evaluate(&selection);
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for Knapsack {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Maximization
+    }
+    fn optimum(&self) -> f64 {
+        self.instance().optimum()
+    }
+}
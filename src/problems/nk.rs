@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use super::{BinaryGene, GenotypeT, Problem, ProblemSolving, bits};
+
+/**
+ * Instance of an NK landscape. Each of the N bits contributes with a value from its table, depending on the bit
+ * and on the K bits that follow it, in a circular way.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NkInstance {
+    pub n: usize,
+    pub k: usize,
+    //Table of contributions of each bit, indexed by the bit and its K neighbours, the bit being the most significant one
+    pub contributions: Vec<Vec<f64>>,
+}
+
+impl NkInstance {
+    /**
+     * Creates an instance with random contributions in [0, 1), generated from the seed
+     */
+    pub fn new(n: usize, k: usize, seed: u64) -> Self {
+        if k >= n {
+            panic!("The K of the NK landscape must be smaller than N.");
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let contributions = (0..n).map(|_| (0..1usize << (k + 1)).map(|_| rng.gen_range(0.0..1.0)).collect()).collect();
+        NkInstance { n, k, contributions }
+    }
+
+    /**
+     * Function to get the fitness of a solution, the mean of the contributions of the bits
+     */
+    pub fn evaluate(&self, solution: &[bool]) -> f64 {
+        (0..self.n).map(|i| self.contribution(i, solution)).sum::<f64>() / self.n as f64
+    }
+
+    /**
+     * Function to get the contribution of a bit in a solution
+     */
+    fn contribution(&self, i: usize, solution: &[bool]) -> f64 {
+        let index = (0..=self.k).fold(0, |index, j| (index << 1) | solution[(i + j) % self.n] as usize);
+        self.contributions[i][index]
+    }
+
+    /**
+     * Function to get the optimal fitness, by dynamic programming over the last K bits
+     */
+    pub fn optimum(&self) -> f64 {
+        let (n, k) = (self.n, self.k);
+        let mask = (1usize << k) - 1;
+        let mut best = f64::NEG_INFINITY;
+
+        //1- For every value of the first K bits, the circular contributions are added at the end
+        for first in 0..1usize << k {
+            //2- Best sum for every value of the last K bits set, with bits 0..K being the first ones
+            let mut states = vec![f64::NEG_INFINITY; 1 << k];
+            states[first] = 0.0;
+            for i in k..n {
+                let mut next = vec![f64::NEG_INFINITY; 1 << k];
+                for (state, sum) in states.iter().enumerate().filter(|(_, sum)| sum.is_finite()) {
+                    for bit in 0..2 {
+                        //The window of the bit i - K is the state followed by the new bit
+                        let window = (state << 1) | bit;
+                        let value = sum + self.contributions[i - k][window];
+                        let next_state = window & mask;
+                        if value > next[next_state] {
+                            next[next_state] = value;
+                        }
+                    }
+                }
+                states = next;
+            }
+
+            //3- The last K bits wrap around with the first ones
+            for (state, sum) in states.iter().enumerate().filter(|(_, sum)| sum.is_finite()) {
+                let mut solution = vec![false; n];
+                (0..k).for_each(|j| {
+                    solution[j] = (first >> (k - 1 - j)) & 1 == 1;
+                    solution[n - k + j] = (state >> (k - 1 - j)) & 1 == 1;
+                });
+                let total = sum + (n - k..n).map(|i| self.contribution(i, &solution)).sum::<f64>();
+                best = best.max(total);
+            }
+        }
+
+        best / n as f64
+    }
+}
+
+/**
+ * NK landscape: the fitness is the mean of the contributions of the bits, from the instance shared between the individuals.
+ * The instance must be set before calculating the fitness, with a prototype individual for the random initialization.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NkLandscape {
+    pub dna: Vec<BinaryGene>,
+    pub fitness: f64,
+    pub age: i32,
+    pub instance: Option<Arc<NkInstance>>,
+}
+impl NkLandscape {
+    /**
+     * Creates an empty individual of the instance, to be used as a prototype
+     */
+    pub fn with_instance(instance: Arc<NkInstance>) -> Self {
+        NkLandscape { instance: Some(instance), ..Default::default() }
+    }
+
+    fn instance(&self) -> &NkInstance {
+        self.instance.as_deref().expect("The NK landscape individual has no instance.")
+    }
+}
+impl GenotypeT for NkLandscape {
+    type Gene = BinaryGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        let solution: Vec<bool> = bits(&self.dna).collect();
+        self.fitness = self.instance().evaluate(&solution);
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for NkLandscape {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Maximization
+    }
+    fn optimum(&self) -> f64 {
+        self.instance().optimum()
+    }
+}
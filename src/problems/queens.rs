@@ -0,0 +1,65 @@
+use super::{IntegerGene, GenotypeT, Problem, ProblemSolving};
+
+/**
+ * N-Queens: the dna is a permutation with the row of the queen of each column, so only the diagonals can be attacked.
+ * The fitness is the number of pairs of queens attacking each other, to be minimized. The optimum is 0 for N = 1 and N >= 4.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NQueens {
+    pub dna: Vec<IntegerGene>,
+    pub fitness: f64,
+    pub age: i32,
+}
+impl NQueens {
+    /**
+     * Rows of the board, to be used as alleles without repetition
+     */
+    pub fn alleles(n: usize) -> Vec<IntegerGene> {
+        (0..n as i32).map(|id| IntegerGene { id }).collect()
+    }
+}
+impl GenotypeT for NQueens {
+    type Gene = IntegerGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        let mut attacks = 0;
+        for i in 0..self.dna.len() {
+            for j in i + 1..self.dna.len() {
+                let rows = (self.dna[i].id - self.dna[j].id).unsigned_abs() as usize;
+                if rows == 0 || rows == j - i {
+                    attacks += 1;
+                }
+            }
+        }
+        self.fitness = attacks as f64;
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for NQueens {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Minimization
+    }
+    fn optimum(&self) -> f64 {
+        0.0
+    }
+}
@@ -0,0 +1,439 @@
+use std::{collections::HashMap, fmt, fs, path::Path, sync::Arc};
+use crate::{ga::Ga, traits::ConfigurationT, initialization::heuristic::Heuristic};
+use super::{IntegerGene, GenotypeT, Problem, ProblemSolving};
+
+/**
+ * Error of the parsing of a TSPLIB file
+ */
+#[derive(Debug)]
+pub enum TsplibError {
+    //The file can't be read
+    Io(std::io::Error),
+    //The content is not a valid or supported TSPLIB file
+    Parse(String),
+}
+impl fmt::Display for TsplibError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TsplibError::Io(error) => write!(f, "The TSPLIB file can't be read: {}", error),
+            TsplibError::Parse(message) => write!(f, "The TSPLIB file can't be parsed: {}", message),
+        }
+    }
+}
+impl std::error::Error for TsplibError {}
+impl From<std::io::Error> for TsplibError {
+    fn from(error: std::io::Error) -> Self {
+        TsplibError::Io(error)
+    }
+}
+
+/**
+ * Way the distances between the cities are given, from the supported TSPLIB edge weight types
+ */
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeWeightType {
+    //Euclidean distance rounded to the nearest integer
+    Euc2d,
+    //Geographical distance over the coordinates in degrees and minutes
+    Geo,
+    //Pseudo-Euclidean distance
+    Att,
+    //Distances given in a matrix
+    Explicit,
+}
+
+/**
+ * Instance of the travelling salesman problem, with the distances between the cities. The cities are numbered from 0.
+ */
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TspInstance {
+    pub name: String,
+    pub dimension: usize,
+    pub edge_weight_type: EdgeWeightType,
+    //Coordinates of the cities, empty for the explicit distances
+    pub coordinates: Vec<(f64, f64)>,
+    //Distances between every pair of cities, by rows
+    pub distances: Vec<f64>,
+    //Optimal tour, if it is known
+    pub optimal_tour: Option<Vec<usize>>,
+}
+
+impl TspInstance {
+    /**
+     * Parses the content of a TSPLIB .tsp file. The supported edge weight types are EUC_2D, GEO, ATT and EXPLICIT.
+     */
+    pub fn from_tsplib_str(content: &str) -> Result<Self, TsplibError> {
+        let (specification, sections) = parse_sections(content)?;
+
+        //1- Specification of the instance
+        let name = specification.get("NAME").cloned().unwrap_or_default();
+        let dimension = dimension(&specification)?.ok_or_else(|| TsplibError::Parse(String::from("The DIMENSION is missing.")))?;
+        let edge_weight_type = match specification.get("EDGE_WEIGHT_TYPE").map(String::as_str) {
+            Some("EUC_2D") => EdgeWeightType::Euc2d,
+            Some("GEO") => EdgeWeightType::Geo,
+            Some("ATT") => EdgeWeightType::Att,
+            Some("EXPLICIT") => EdgeWeightType::Explicit,
+            Some(other) => return Err(TsplibError::Parse(format!("The edge weight type {} is not supported.", other))),
+            None => return Err(TsplibError::Parse(String::from("The EDGE_WEIGHT_TYPE is missing."))),
+        };
+
+        //2- Distances, from the matrix or from the coordinates of the cities
+        let (coordinates, distances) = if edge_weight_type == EdgeWeightType::Explicit {
+            let format = specification.get("EDGE_WEIGHT_FORMAT").map(String::as_str).unwrap_or("FULL_MATRIX");
+            let weights = numbers::<f64>(sections.get("EDGE_WEIGHT_SECTION"), "EDGE_WEIGHT_SECTION")?;
+            (Vec::new(), explicit_distances(dimension, format, &weights)?)
+        } else {
+            let coordinates = node_coordinates(dimension, sections.get("NODE_COORD_SECTION"))?;
+            let mut distances = vec![0.0; dimension * dimension];
+            for i in 0..dimension {
+                for j in 0..dimension {
+                    if i != j {
+                        distances[i * dimension + j] = match edge_weight_type {
+                            EdgeWeightType::Euc2d => euclidean(coordinates[i], coordinates[j]),
+                            EdgeWeightType::Geo => geographical(coordinates[i], coordinates[j]),
+                            _ => pseudo_euclidean(coordinates[i], coordinates[j]),
+                        };
+                    }
+                }
+            }
+            (coordinates, distances)
+        };
+
+        Ok(TspInstance { name, dimension, edge_weight_type, coordinates, distances, optimal_tour: None })
+    }
+
+    /**
+     * Reads and parses a TSPLIB .tsp file
+     */
+    pub fn from_tsplib_file<P: AsRef<Path>>(path: P) -> Result<Self, TsplibError> {
+        Self::from_tsplib_str(&fs::read_to_string(path)?)
+    }
+
+    /**
+     * Function to set the optimal tour of the instance, a permutation of the cities
+     */
+    pub fn with_optimal_tour(&mut self, tour: Vec<usize>) -> &mut Self {
+        if !is_permutation(&tour, self.dimension) {
+            panic!("The optimal tour must be a permutation of the cities of the instance.");
+        }
+        self.optimal_tour = Some(tour);
+        self
+    }
+
+    /**
+     * Function to get the distance from a city to another one
+     */
+    pub fn distance(&self, from: usize, to: usize) -> f64 {
+        self.distances[from * self.dimension + to]
+    }
+
+    /**
+     * Function to get the length of a closed tour
+     */
+    pub fn tour_length(&self, tour: &[usize]) -> f64 {
+        if tour.is_empty() {
+            return 0.0;
+        }
+        tour.windows(2).map(|pair| self.distance(pair[0], pair[1])).sum::<f64>() + self.distance(tour[tour.len() - 1], tour[0])
+    }
+
+    /**
+     * Function to get the tour built by going from the start city to the nearest city not visited yet
+     */
+    pub fn nearest_neighbour_tour(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.dimension];
+        let mut tour = Vec::with_capacity(self.dimension);
+        let mut city = start;
+        while tour.len() < self.dimension {
+            visited[city] = true;
+            tour.push(city);
+            if let Some(next) = (0..self.dimension).filter(|next| !visited[*next]).min_by(|a, b| self.distance(city, *a).total_cmp(&self.distance(city, *b))) {
+                city = next;
+            }
+        }
+        tour
+    }
+
+    /**
+     * Cities of the instance, to be used as alleles without repetition
+     */
+    pub fn alleles(&self) -> Vec<IntegerGene> {
+        (0..self.dimension as i32).map(|id| IntegerGene { id }).collect()
+    }
+}
+
+/**
+ * Parses the content of a TSPLIB .opt.tour file, returning the cities of the tour numbered from 0
+ */
+pub fn tour_from_tsplib_str(content: &str) -> Result<Vec<usize>, TsplibError> {
+    let (specification, sections) = parse_sections(content)?;
+    let nodes = numbers::<i64>(sections.get("TOUR_SECTION"), "TOUR_SECTION")?;
+
+    //The tour ends with -1
+    let mut tour = Vec::new();
+    for node in nodes.into_iter().take_while(|node| *node != -1) {
+        if node < 1 {
+            return Err(TsplibError::Parse(format!("The node {} of the tour is not valid.", node)));
+        }
+        tour.push(node as usize - 1);
+    }
+
+    let dimension = dimension(&specification)?.unwrap_or(tour.len());
+    if !is_permutation(&tour, dimension) {
+        return Err(TsplibError::Parse(format!("The tour is not a permutation of the {} nodes.", dimension)));
+    }
+    Ok(tour)
+}
+
+/**
+ * Reads and parses a TSPLIB .opt.tour file
+ */
+pub fn tour_from_tsplib_file<P: AsRef<Path>>(path: P) -> Result<Vec<usize>, TsplibError> {
+    tour_from_tsplib_str(&fs::read_to_string(path)?)
+}
+
+/**
+ * Tour of the travelling salesman problem: the dna is a permutation of the cities, in the ids of the genes,
+ * and the fitness is the length of the closed tour, to be minimized.
+ * The instance must be set before calculating the fitness, with a prototype individual for the random initialization.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tour {
+    pub dna: Vec<IntegerGene>,
+    pub fitness: f64,
+    pub age: i32,
+    pub instance: Option<Arc<TspInstance>>,
+}
+impl Tour {
+    /**
+     * Creates an empty individual of the instance, to be used as a prototype
+     */
+    pub fn with_instance(instance: Arc<TspInstance>) -> Self {
+        Tour { instance: Some(instance), ..Default::default() }
+    }
+
+    /**
+     * Creates a Ga minimizing the length of the tours of the instance. The random initialization creates permutations of the cities,
+     * without repeated alleles, and the ids of the genes are not replaced as they are the cities. The operators are still to be set.
+     */
+    pub fn ga(instance: Arc<TspInstance>) -> Ga<Tour> {
+        let mut ga = Ga::new();
+        ga.with_problem_solving(ProblemSolving::Minimization)
+            .with_genes_per_individual(instance.dimension as i32)
+            .with_alleles_can_be_repeated(false)
+            .with_needs_unique_ids(false);
+        ga.with_alleles(instance.alleles())
+            .with_prototype(Tour::with_instance(instance));
+        ga
+    }
+
+    /**
+     * Heuristic seeding with the nearest neighbour tours from the first cities, for the given number of individuals
+     */
+    pub fn nearest_neighbour(instance: Arc<TspInstance>, count: usize) -> Heuristic<Tour> {
+        Heuristic::new(count, move |index| instance.nearest_neighbour_tour(index % instance.dimension).into_iter()
+            .map(|city| IntegerGene { id: city as i32 }).collect())
+    }
+
+    /**
+     * Function to get the cities of the tour
+     */
+    pub fn cities(&self) -> Vec<usize> {
+        self.dna.iter().map(|gene| gene.id as usize).collect()
+    }
+
+    fn instance(&self) -> &TspInstance {
+        self.instance.as_deref().expect("The tour has no instance.")
+    }
+}
+impl GenotypeT for Tour {
+    type Gene = IntegerGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene]) -> &mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        self.fitness = self.instance().tour_length(&self.cities());
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64) -> &mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32) -> &mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+}
+impl Problem for Tour {
+    fn problem_solving() -> ProblemSolving {
+        ProblemSolving::Minimization
+    }
+    //The length of the optimal tour of the instance, or NaN if it is not known
+    fn optimum(&self) -> f64 {
+        let instance = self.instance();
+        instance.optimal_tour.as_ref().map_or(f64::NAN, |tour| instance.tour_length(tour))
+    }
+}
+
+//Data of each section of a TSPLIB file, split in tokens
+type Sections = HashMap<String, Vec<String>>;
+
+/**
+ * Splits the content of a TSPLIB file in the specification entries (KEY : VALUE) and the data of the sections
+ */
+fn parse_sections(content: &str) -> Result<(HashMap<String, String>, Sections), TsplibError> {
+    let mut specification = HashMap::new();
+    let mut sections: Sections = HashMap::new();
+    let mut section: Option<String> = None;
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        //The keywords start with a letter, and the data of the sections with a number
+        if line.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            if line == "EOF" {
+                break;
+            }
+            match line.split_once(':') {
+                Some((key, value)) if !value.trim().is_empty() => {
+                    specification.insert(key.trim().to_uppercase(), value.trim().to_string());
+                    section = None;
+                },
+                _ => {
+                    let name = line.trim_end_matches(':').trim().to_uppercase();
+                    sections.entry(name.clone()).or_default();
+                    section = Some(name);
+                },
+            }
+        } else {
+            match &section {
+                Some(name) => sections.get_mut(name).unwrap().extend(line.split_whitespace().map(String::from)),
+                None => return Err(TsplibError::Parse(format!("The line {} is data outside of a section.", number + 1))),
+            }
+        }
+    }
+
+    Ok((specification, sections))
+}
+
+/**
+ * Gets the dimension of the specification, if it is set
+ */
+fn dimension(specification: &HashMap<String, String>) -> Result<Option<usize>, TsplibError> {
+    specification.get("DIMENSION").map(|dimension| dimension.parse::<usize>()
+        .map_err(|_| TsplibError::Parse(format!("The DIMENSION {} is not valid.", dimension)))).transpose()
+}
+
+/**
+ * Parses the numbers of a section
+ */
+fn numbers<T: std::str::FromStr>(tokens: Option<&Vec<String>>, section: &str) -> Result<Vec<T>, TsplibError> {
+    tokens.ok_or_else(|| TsplibError::Parse(format!("The {} is missing.", section)))?
+        .iter().map(|token| token.parse::<T>().map_err(|_| TsplibError::Parse(format!("The value {} of the {} is not a number.", token, section))))
+        .collect()
+}
+
+/**
+ * Parses the coordinates of the cities, given as node x y
+ */
+fn node_coordinates(dimension: usize, tokens: Option<&Vec<String>>) -> Result<Vec<(f64, f64)>, TsplibError> {
+    let values = numbers::<f64>(tokens, "NODE_COORD_SECTION")?;
+    if values.len() != dimension * 3 {
+        return Err(TsplibError::Parse(format!("The NODE_COORD_SECTION must have {} nodes with 2 coordinates.", dimension)));
+    }
+
+    let mut coordinates = vec![None; dimension];
+    for node in values.chunks(3) {
+        let index = node[0] as usize;
+        if node[0].fract() != 0.0 || index < 1 || index > dimension || coordinates[index - 1].is_some() {
+            return Err(TsplibError::Parse(format!("The node {} of the NODE_COORD_SECTION is not valid.", node[0])));
+        }
+        coordinates[index - 1] = Some((node[1], node[2]));
+    }
+    Ok(coordinates.into_iter().map(|coordinate| coordinate.unwrap()).collect())
+}
+
+/**
+ * Builds the distances from the weights of an explicit matrix in the given format
+ */
+fn explicit_distances(dimension: usize, format: &str, weights: &[f64]) -> Result<Vec<f64>, TsplibError> {
+    let mut distances = vec![0.0; dimension * dimension];
+
+    //1- Cells of the matrix in the order of the weights. The column formats of a symmetric matrix are the row formats of the other triangle.
+    let cells: Vec<(usize, usize)> = match format {
+        "FULL_MATRIX" => (0..dimension).flat_map(|i| (0..dimension).map(move |j| (i, j))).collect(),
+        "UPPER_ROW" | "LOWER_COL" => (0..dimension).flat_map(|i| (i + 1..dimension).map(move |j| (i, j))).collect(),
+        "LOWER_ROW" | "UPPER_COL" => (0..dimension).flat_map(|i| (0..i).map(move |j| (i, j))).collect(),
+        "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => (0..dimension).flat_map(|i| (i..dimension).map(move |j| (i, j))).collect(),
+        "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => (0..dimension).flat_map(|i| (0..=i).map(move |j| (i, j))).collect(),
+        other => return Err(TsplibError::Parse(format!("The edge weight format {} is not supported.", other))),
+    };
+    if weights.len() != cells.len() {
+        return Err(TsplibError::Parse(format!("The EDGE_WEIGHT_SECTION must have {} weights for the {} format, and it has {}.", cells.len(), format, weights.len())));
+    }
+
+    //2- The triangular formats are symmetric
+    for ((i, j), weight) in cells.into_iter().zip(weights) {
+        distances[i * dimension + j] = *weight;
+        if format != "FULL_MATRIX" {
+            distances[j * dimension + i] = *weight;
+        }
+    }
+    Ok(distances)
+}
+
+/**
+ * Euclidean distance rounded to the nearest integer (EUC_2D)
+ */
+fn euclidean(from: (f64, f64), to: (f64, f64)) -> f64 {
+    ((from.0 - to.0).powi(2) + (from.1 - to.1).powi(2)).sqrt().round()
+}
+
+/**
+ * Pseudo-Euclidean distance (ATT)
+ */
+fn pseudo_euclidean(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let distance = (((from.0 - to.0).powi(2) + (from.1 - to.1).powi(2)) / 10.0).sqrt();
+    let rounded = distance.round();
+    if rounded < distance {rounded + 1.0} else {rounded}
+}
+
+/**
+ * Geographical distance in kilometres (GEO), with the coordinates in degrees and minutes as DDD.MM
+ */
+#[allow(clippy::approx_constant)]
+fn geographical(from: (f64, f64), to: (f64, f64)) -> f64 {
+    //TSPLIB uses this approximation of pi and of the radius of the Earth
+    let radians = |coordinate: f64| {
+        let degrees = coordinate.trunc();
+        3.141592 * (degrees + 5.0 * (coordinate - degrees) / 3.0) / 180.0
+    };
+    let (latitude_from, longitude_from, latitude_to, longitude_to) = (radians(from.0), radians(from.1), radians(to.0), radians(to.1));
+    let q1 = (longitude_from - longitude_to).cos();
+    let q2 = (latitude_from - latitude_to).cos();
+    let q3 = (latitude_from + latitude_to).cos();
+    (6378.388 * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0).trunc()
+}
+
+/**
+ * Checks if the tour visits every city once
+ */
+fn is_permutation(tour: &[usize], dimension: usize) -> bool {
+    let mut visited = vec![false; dimension];
+    tour.len() == dimension && tour.iter().all(|city| *city < dimension && !std::mem::replace(&mut visited[*city], true))
+}
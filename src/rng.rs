@@ -0,0 +1,58 @@
+use std::cell::RefCell;
+use rand::{rngs::{StdRng, ThreadRng}, RngCore, SeedableRng};
+
+thread_local! {
+    //Seeded generator of the current thread, if a seed has been set
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/**
+ * Sets the seed of the random numbers of the current thread. The runs executed in this thread with the same seed
+ * and with a single thread in the configuration are reproducible. With None, the random numbers are not seeded.
+ */
+pub fn set_seed(seed: Option<u64>){
+    SEEDED_RNG.with(|rng| *rng.borrow_mut() = seed.map(StdRng::seed_from_u64));
+}
+
+/**
+ * Gets the seed for a thread spawned from the current one. It's only some if the current thread is seeded.
+ */
+pub(crate) fn spawn_seed() -> Option<u64>{
+    SEEDED_RNG.with(|rng| rng.borrow_mut().as_mut().map(|rng| rng.next_u64()))
+}
+
+/**
+ * Gets the random number generator of the current thread
+ */
+pub(crate) fn rng() -> GaRng{
+    GaRng { thread_rng: rand::thread_rng() }
+}
+
+/**
+ * Random number generator that uses the seeded generator of the thread if it is set, and the thread generator otherwise
+ */
+pub(crate) struct GaRng{
+    thread_rng: ThreadRng,
+}
+impl GaRng{
+    fn with<T>(&mut self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T{
+        SEEDED_RNG.with(|seeded| match seeded.borrow_mut().as_mut() {
+            Some(rng) => f(rng),
+            None => f(&mut self.thread_rng),
+        })
+    }
+}
+impl RngCore for GaRng{
+    fn next_u32(&mut self) -> u32 {
+        self.with(|rng| rng.next_u32())
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.with(|rng| rng.next_u64())
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(dest))
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.with(|rng| rng.try_fill_bytes(dest))
+    }
+}
@@ -0,0 +1,29 @@
+use std::f64::consts::PI;
+use log::trace;
+use crate::configuration::Schedule;
+
+/**
+ * Function to calculate the probability of a generation for the given schedule.
+ * The probability goes from the probability_max at the first generation to the probability_min, or 0.0 if not set.
+ * Without probability_max, the schedules start from 1.0 and the constant schedule keeps the probability unset.
+ */
+pub fn probability(schedule: Schedule, generation: i32, max_generations: i32, probability_max: Option<f64>, probability_min: Option<f64>) -> Option<f64>{
+
+    if schedule == Schedule::Constant {
+        return probability_max;
+    }
+
+    let (start, end) = (probability_max.unwrap_or(1.0), probability_min.unwrap_or(0.0));
+    let progress = if max_generations > 1 {generation as f64 / (max_generations - 1) as f64} else {0.0};
+
+    let probability = match schedule {
+        Schedule::Constant => {start},
+        Schedule::Linear => {start - (start - end) * progress},
+        Schedule::Exponential { rate } => {end + (start - end) * (-rate * generation as f64).exp()},
+        Schedule::Cosine => {end + 0.5 * (start - end) * (1.0 + (PI * progress).cos())},
+        Schedule::Step { step_size, factor } => {(start * factor.powi(generation / step_size)).max(end)},
+    };
+
+    trace!(target="schedule_events", method="probability"; "Generation {} - probability {}", generation, probability);
+    Some(probability)
+}
@@ -0,0 +1,167 @@
+use crate::{traits::GenotypeT, cache::CacheStatistics};
+
+/**
+ * Diversity of the population
+ */
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diversity {
+    //Mean distance between every pair of individuals
+    pub mean_distance: f64,
+    //Mean over the loci of the entropy of the genes
+    pub entropy: f64,
+    //Number of different genotypes
+    pub unique_genotypes: usize,
+}
+
+/**
+ * Statistics of the population in a generation
+ */
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationStatistics {
+    //Number of the generation
+    pub generation: i32,
+    //Fitness statistics of the population, without the individuals with a NaN fitness
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    //Fitness of the best individual found so far in the run
+    pub best_so_far: f64,
+    //Number of fitness evaluations so far in the run
+    pub evaluations: usize,
+    //Number of fitness evaluations skipped so far in the run, because the fitness of the individuals was still valid
+    pub skipped_evaluations: usize,
+    //Seconds elapsed since the start of the run
+    pub elapsed_seconds: f64,
+    //Crossover and mutation probabilities of the generation
+    pub crossover_probability: f64,
+    pub mutation_probability: f64,
+    //Diversity of the population, only if the diversity statistics are enabled
+    pub diversity: Option<Diversity>,
+    //Use of the fitness cache so far in the run, only if the fitness cache is enabled
+    pub cache: Option<CacheStatistics>,
+}
+
+impl GenerationStatistics {
+
+    /**
+     * Function to calculate the statistics of the individuals of a generation
+     */
+    pub fn new<U: GenotypeT>(generation: i32, individuals: &[U], best_so_far: f64, evaluations: usize, diversity: Option<Diversity>) -> GenerationStatistics {
+        let mut fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).filter(|fitness| !fitness.is_nan()).collect();
+        fitness.sort_by(f64::total_cmp);
+
+        let (min, max, mean, median, std_dev) = if fitness.is_empty() {
+            (f64::NAN, f64::NAN, f64::NAN, f64::NAN, f64::NAN)
+        } else {
+            let n = fitness.len();
+            let mean = fitness.iter().sum::<f64>() / n as f64;
+            let median = if n % 2 == 0 {(fitness[n / 2 - 1] + fitness[n / 2]) / 2.0} else {fitness[n / 2]};
+            let variance = fitness.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / n as f64;
+            (fitness[0], fitness[n - 1], mean, median, variance.sqrt())
+        };
+
+        GenerationStatistics { generation, min, max, mean, median, std_dev, best_so_far, evaluations, skipped_evaluations: 0, elapsed_seconds: 0.0, crossover_probability: 1.0, mutation_probability: 1.0, diversity, cache: None }
+    }
+}
+
+/**
+ * Summary of a sample of values
+ */
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    //First and third quartiles, and the interquartile range between them
+    pub q1: f64,
+    pub q3: f64,
+    pub iqr: f64,
+}
+
+impl Summary {
+
+    /**
+     * Function to summarize a sample of values, without the NaN values. The quartiles are interpolated between the closest values.
+     */
+    pub fn new(values: &[f64]) -> Summary {
+        let mut values: Vec<f64> = values.iter().copied().filter(|value| !value.is_nan()).collect();
+        values.sort_by(f64::total_cmp);
+
+        if values.is_empty() {
+            return Summary { min: f64::NAN, max: f64::NAN, mean: f64::NAN, median: f64::NAN, std_dev: f64::NAN, q1: f64::NAN, q3: f64::NAN, iqr: f64::NAN };
+        }
+        let n = values.len();
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / n as f64;
+        let (q1, q3) = (quantile(&values, 0.25), quantile(&values, 0.75));
+        Summary { min: values[0], max: values[n - 1], mean, median: quantile(&values, 0.5), std_dev: variance.sqrt(), q1, q3, iqr: q3 - q1 }
+    }
+}
+
+/**
+ * Function to get the quantile of sorted values, interpolating linearly between the closest values
+ */
+fn quantile(sorted_values: &[f64], probability: f64) -> f64 {
+    let position = probability * (sorted_values.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted_values[lower] + (sorted_values[upper] - sorted_values[lower]) * (position - lower as f64)
+}
+
+/**
+ * Function to export the statistics history to CSV, with a header and one row per generation.
+ * The diversity and cache columns are empty if the diversity statistics or the fitness cache are not enabled.
+ */
+pub fn to_csv(statistics: &[GenerationStatistics]) -> String {
+    let mut csv = String::from("generation,min,max,mean,median,std_dev,best_so_far,evaluations,skipped_evaluations,elapsed_seconds,crossover_probability,mutation_probability,mean_distance,entropy,unique_genotypes,cache_hits,cache_misses,cache_hit_rate\n");
+    for generation in statistics {
+        let diversity = match generation.diversity {
+            Some(diversity) => format!("{},{},{}", diversity.mean_distance, diversity.entropy, diversity.unique_genotypes),
+            None => String::from(",,"),
+        };
+        let cache = match generation.cache {
+            Some(cache) => format!("{},{},{}", cache.hits, cache.misses, cache.hit_rate),
+            None => String::from(",,"),
+        };
+        csv.push_str(&format!("{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n", generation.generation, generation.min, generation.max, generation.mean, generation.median,
+                              generation.std_dev, generation.best_so_far, generation.evaluations, generation.skipped_evaluations, generation.elapsed_seconds, 
+                              generation.crossover_probability, generation.mutation_probability, diversity, cache));
+    }
+    csv
+}
+
+/**
+ * Function to export the statistics history to JSON Lines, with one JSON object per generation.
+ * The values that are not numbers, and the diversity and the cache if they are not enabled, are null.
+ */
+pub fn to_json_lines(statistics: &[GenerationStatistics]) -> String {
+    let mut json_lines = String::new();
+    for generation in statistics {
+        let diversity = match generation.diversity {
+            Some(diversity) => format!("{{\"mean_distance\":{},\"entropy\":{},\"unique_genotypes\":{}}}", json_number(diversity.mean_distance), json_number(diversity.entropy), diversity.unique_genotypes),
+            None => String::from("null"),
+        };
+        let cache = match generation.cache {
+            Some(cache) => format!("{{\"hits\":{},\"misses\":{},\"hit_rate\":{}}}", cache.hits, cache.misses, json_number(cache.hit_rate)),
+            None => String::from("null"),
+        };
+        json_lines.push_str(&format!("{{\"generation\":{},\"min\":{},\"max\":{},\"mean\":{},\"median\":{},\"std_dev\":{},\"best_so_far\":{},\"evaluations\":{},\"skipped_evaluations\":{},\"elapsed_seconds\":{},\"crossover_probability\":{},\"mutation_probability\":{},\"diversity\":{},\"cache\":{}}}\n",
+                                     generation.generation, json_number(generation.min), json_number(generation.max), json_number(generation.mean), json_number(generation.median),
+                                     json_number(generation.std_dev), json_number(generation.best_so_far), generation.evaluations, generation.skipped_evaluations, json_number(generation.elapsed_seconds),
+                                     json_number(generation.crossover_probability), json_number(generation.mutation_probability), diversity, cache));
+    }
+    json_lines
+}
+
+/**
+ * Function to write a number in JSON, where NaN and infinite are not valid
+ */
+pub(crate) fn json_number(number: f64) -> String {
+    if number.is_finite() {number.to_string()} else {String::from("null")}
+}
\ No newline at end of file
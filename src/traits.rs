@@ -1,4 +1,4 @@
-use crate::{configuration::{LogLevel, ProblemSolving}, operations::{Survivor, Selection, Crossover, Mutation}};
+use crate::{configuration::{LogLevel, ProblemSolving, Schedule, Distance, AgaFormulation, HistoryFormat}, operations::{Survivor, Selection, Crossover, Mutation, OperatorSelection, Replacement, Niching}};
 
 pub trait GeneT: Default + Clone + Sync + Send {
     fn new() -> Self{
@@ -10,6 +10,14 @@ pub trait GeneT: Default + Clone + Sync + Send {
     }
     fn get_id(&self) -> i32{0}
     fn set_id(&mut self, id: i32) -> &mut Self;
+
+    //Numeric value of the gene, used by the numeric operators. By default, the id of the gene
+    fn get_value(&self) -> f64{
+        self.get_id() as f64
+    }
+    fn set_value(&mut self, value: f64) -> &mut Self{
+        self.set_id(value.round() as i32)
+    }
 }
 
 pub trait GenotypeT: Clone + Default{
@@ -45,14 +53,45 @@ pub trait GenotypeT: Clone + Default{
     fn get_fitness_distance(&self, fitness_target: &f64) -> f64 {
         (fitness_target - self.get_fitness()).abs()
     }
+
+    //Distance between two genotypes, used by the niching methods. By default, the Hamming distance over the gene ids
+    fn get_distance(&self, other: &Self) -> f64 {
+        crate::distance::hamming(self, other)
+    }
+
+    //Self-adaptive strategy parameters (mutation step sizes). Genotypes without them are not self-adaptive
+    fn get_strategy_parameters(&self) -> &[f64] {
+        &[]
+    }
+    fn set_strategy_parameters(&mut self, _strategy_parameters: &[f64]) -> &mut Self {
+        self
+    }
+
+    //Whether the fitness belongs to the current dna, so it's not calculated again. Genotypes without the flag are always evaluated
+    fn is_fitness_valid(&self) -> bool {
+        false
+    }
+    fn set_fitness_valid(&mut self, _fitness_valid: bool) -> &mut Self {
+        self
+    }
 }
 
 pub trait ConfigurationT{
     fn new() -> Self;
     fn with_adaptive_ga(&mut self, adaptive_ga: bool) -> &mut Self;
+    fn with_aga_formulation(&mut self, aga_formulation: AgaFormulation) -> &mut Self;
     fn with_threads(&mut self, number_of_threads: i32)-> &mut Self;
     fn with_logs(&mut self, log_level: LogLevel) -> &mut Self;
     fn with_survivor_method(&mut self, method: Survivor) -> &mut Self;
+    fn with_lambda(&mut self, lambda: i32) -> &mut Self;
+    fn with_fitness_cache(&mut self, fitness_cache_size: i32) -> &mut Self;
+
+    //Niching configuration
+    fn with_niching_method(&mut self, method: Niching) -> &mut Self;
+    fn with_sigma_share(&mut self, sigma_share: f64) -> &mut Self;
+    fn with_sharing_alpha(&mut self, alpha: f64) -> &mut Self;
+    fn with_niche_capacity(&mut self, niche_capacity: i32) -> &mut Self;
+    fn with_window_size(&mut self, window_size: i32) -> &mut Self;
 
     //Limit configuration
     fn with_problem_solving(&mut self, problem_solving: ProblemSolving)->&mut Self;
@@ -73,15 +112,42 @@ pub trait ConfigurationT{
     fn with_crossover_probability_max(&mut self, probability_max: f64)->&mut Self;
     fn with_crossover_probability_min(&mut self, probability_min: f64) -> &mut Self;
     fn with_crossover_method(&mut self, method: Crossover) -> &mut Self;
+    fn with_crossover_schedule(&mut self, schedule: Schedule) -> &mut Self;
 
     //Mutation configuration
     fn with_mutation_probability_max(&mut self, probability_max: f64)->&mut Self;
     fn with_mutation_probability_min(&mut self, probability_min: f64) -> &mut Self;
     fn with_mutation_method(&mut self, method: Mutation) -> &mut Self;
+    fn with_mutation_schedule(&mut self, schedule: Schedule) -> &mut Self;
+    fn with_mutation_step_size(&mut self, step_size: f64) -> &mut Self;
+
+    //Operator selection configuration
+    fn with_operator_selection_method(&mut self, method: OperatorSelection) -> &mut Self;
+    fn with_crossover_operators(&mut self, operators: Vec<Crossover>) -> &mut Self;
+    fn with_mutation_operators(&mut self, operators: Vec<Mutation>) -> &mut Self;
+    fn with_operator_selection_probability_min(&mut self, probability_min: f64) -> &mut Self;
+    fn with_operator_selection_adaptation_rate(&mut self, adaptation_rate: f64) -> &mut Self;
+    fn with_operator_selection_learning_rate(&mut self, learning_rate: f64) -> &mut Self;
+    fn with_operator_selection_exploration(&mut self, exploration: f64) -> &mut Self;
+
+    //Steady state configuration
+    fn with_steady_state(&mut self, steady_state: bool) -> &mut Self;
+    fn with_offspring_per_step(&mut self, offspring_per_step: i32) -> &mut Self;
+    fn with_replacement_method(&mut self, method: Replacement) -> &mut Self;
+
+    //Statistics configuration
+    fn with_diversity_statistics(&mut self, diversity: bool) -> &mut Self;
+    fn with_distance_metric(&mut self, distance: Distance) -> &mut Self;
 
     //Save progress configuration
     fn with_save_progress(&mut self, save_progress: bool) -> &mut Self;
     fn with_save_progress_interval(&mut self, save_progress_interval: i32) -> &mut Self;
     fn with_save_progress_path(&mut self, save_progress_path: String) -> &mut Self;
 
+    //History configuration
+    fn with_export_history(&mut self, export_history: bool) -> &mut Self;
+    fn with_history_path(&mut self, history_path: String) -> &mut Self;
+    fn with_history_format(&mut self, history_format: HistoryFormat) -> &mut Self;
+    fn with_best_individual_path(&mut self, best_individual_path: String) -> &mut Self;
+
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 #[cfg(test)]
-use crate::structures::{Gene, Genotype};
-use genetic_algorithms::operations::crossover::{cycle, multipoint::multipoint_crossover, uniform_crossover, aga_probability};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
+use genetic_algorithms::operations::crossover::{self, cycle, multipoint::multipoint_crossover, uniform_crossover, aga_probability, aga_probability_with_limit_configuration, srinivas_patnaik_probability};
+use genetic_algorithms::configuration::{LimitConfiguration, ProblemSolving};
+use genetic_algorithms::configuration::CrossoverConfiguration;
 
 
 #[test]
@@ -26,13 +28,13 @@ fn test_cycle_crossover(){
     assert_eq!(parent_1.dna.len(), parent_2.dna.len());
 
     //Checking that the crossover has been well executed for the child 1
-    assert_eq!(child_2.dna.get(0).unwrap().id, 4);
+    assert_eq!(child_2.dna.first().unwrap().id, 4);
     assert_eq!(child_2.dna.get(1).unwrap().id, 2);
     assert_eq!(child_2.dna.get(2).unwrap().id, 3);
     assert_eq!(child_2.dna.get(3).unwrap().id, 1);
 
     //Checking that the crossover has been well executed for the child 2
-    assert_eq!(child_1.dna.get(0).unwrap().id, 1);
+    assert_eq!(child_1.dna.first().unwrap().id, 1);
     assert_eq!(child_1.dna.get(1).unwrap().id, 3);
     assert_eq!(child_1.dna.get(2).unwrap().id, 2);
     assert_eq!(child_1.dna.get(3).unwrap().id, 4);
@@ -63,7 +65,7 @@ fn test_multipoint_crossover_2_points(){
     assert_eq!(parent_1.dna.len(), parent_2.dna.len());
 
     //Checking that the crossover has been well executed for the child 1
-    assert_eq!(child_1.dna.get(0).unwrap().id, 1);
+    assert_eq!(child_1.dna.first().unwrap().id, 1);
     assert_eq!(child_1.dna.get(1).unwrap().id, 2);
     assert_eq!(child_1.dna.get(2).unwrap().id, 4);
     assert_eq!(child_1.dna.get(3).unwrap().id, 3);
@@ -71,7 +73,7 @@ fn test_multipoint_crossover_2_points(){
     assert_eq!(child_1.dna.get(5).unwrap().id, 6);
 
     //Checking that the crossover has been well executed for the child 2
-    assert_eq!(child_2.dna.get(0).unwrap().id, 6);
+    assert_eq!(child_2.dna.first().unwrap().id, 6);
     assert_eq!(child_2.dna.get(1).unwrap().id, 5);
     assert_eq!(child_2.dna.get(2).unwrap().id, 3);
     assert_eq!(child_2.dna.get(3).unwrap().id, 4);
@@ -102,7 +104,7 @@ fn test_multipoint_crossover_4_points(){
     assert_eq!(parent_1.dna.len(), parent_2.dna.len());
 
     //Checking that the crossover has been well executed for the child 1
-    assert_eq!(child_1.dna.get(0).unwrap().id, 1);
+    assert_eq!(child_1.dna.first().unwrap().id, 1);
     assert_eq!(child_1.dna.get(1).unwrap().id, 5);
     assert_eq!(child_1.dna.get(2).unwrap().id, 3);
     assert_eq!(child_1.dna.get(3).unwrap().id, 3);
@@ -110,7 +112,7 @@ fn test_multipoint_crossover_4_points(){
     assert_eq!(child_1.dna.get(5).unwrap().id, 1);
 
     //Checking that the crossover has been well executed for the child 2
-    assert_eq!(child_2.dna.get(0).unwrap().id, 6);
+    assert_eq!(child_2.dna.first().unwrap().id, 6);
     assert_eq!(child_2.dna.get(1).unwrap().id, 2);
     assert_eq!(child_2.dna.get(2).unwrap().id, 4);
     assert_eq!(child_2.dna.get(3).unwrap().id, 4);
@@ -174,4 +176,60 @@ fn test_xover_aga_probability_under_avg(){
 
     //We verify the result of the aga crossover probability
     assert_eq!(aga_xover_probability, 0.25);
+}
+
+#[test]
+fn test_xover_aga_probability_minimization(){
+
+    //In minimization problems, the scores are the opposite of the fitness: the best fitness is 10 and the average one is 50
+    let parent_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 30.0, age: 0};
+    let parent_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 80.0, age: 0};
+    let minimization = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+
+    let aga_xover_probability = aga_probability_with_limit_configuration(&parent_1, &parent_2, -10.0, -50.0, 0.8, 0.2, &minimization);
+    assert_eq!(aga_xover_probability, 0.4);
+}
+
+#[test]
+fn test_xover_aga_probability_converged(){
+
+    //When the best fitness is the average one, the ratio can't be calculated and the maximum probability is used
+    let parent_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 50.0, age: 0};
+    let parent_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 50.0, age: 0};
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+
+    let aga_xover_probability = aga_probability_with_limit_configuration(&parent_1, &parent_2, 50.0, 50.0, 0.75, 0.25, &maximization);
+    assert_eq!(aga_xover_probability, 0.75);
+    assert!(!aga_xover_probability.is_nan());
+}
+
+#[test]
+fn test_xover_srinivas_patnaik_probability(){
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+
+    //Over the average fitness, the probability decreases linearly down to probability_min for the best individual
+    let parent_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 25.0, age: 0};
+    let parent_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 100.0, age: 0};
+    assert_eq!(srinivas_patnaik_probability(&parent_1, &parent_2, 150.0, 50.0, 0.75, 0.25, &maximization), 0.375);
+    let parent_3 = Genotype{dna: Vec::<Gene>::new(), fitness: 150.0, age: 0};
+    assert_eq!(srinivas_patnaik_probability(&parent_1, &parent_3, 150.0, 50.0, 0.75, 0.25, &maximization), 0.25);
+
+    //Under the average fitness, the parents are always crossed with probability_max
+    let parent_4 = Genotype{dna: Vec::<Gene>::new(), fitness: 49.0, age: 0};
+    assert_eq!(srinivas_patnaik_probability(&parent_1, &parent_4, 150.0, 50.0, 0.75, 0.25, &maximization), 0.75);
+}
+
+#[test]
+fn test_crossover_strategy_parameters(){
+    let dna_1 = vec![RealGene{id: 0, value: 1.0}, RealGene{id: 1, value: 2.0}];
+    let dna_2 = vec![RealGene{id: 0, value: 3.0}, RealGene{id: 1, value: 4.0}];
+
+    let parent_1 = SelfAdaptiveGenotype{dna: dna_1, strategy_parameters: vec![1.0, 2.0], fitness: 0.0, age: 0};
+    let parent_2 = SelfAdaptiveGenotype{dna: dna_2, strategy_parameters: vec![3.0, 6.0], fitness: 0.0, age: 0};
+
+    //Both children get the average of the step sizes of their parents
+    let offspring = crossover::factory(&parent_1, &parent_2, CrossoverConfiguration{..Default::default()}).unwrap();
+    for child in offspring {
+        assert_eq!(child.strategy_parameters, vec![2.0, 4.0]);
+    }
 }
\ No newline at end of file
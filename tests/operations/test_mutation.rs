@@ -1,6 +1,8 @@
 #[cfg(test)]
-use crate::structures::{Gene, Genotype};
-use genetic_algorithms::operations::mutation::{swap, inversion, scramble, aga_probability};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
+use genetic_algorithms::operations::{Mutation, mutation::{self, swap, inversion, scramble, gaussian, creep, self_adaptive, random_resetting, aga_probability, srinivas_patnaik_probability}};
+use genetic_algorithms::domain::Domain;
+use genetic_algorithms::configuration::{MutationConfiguration, LimitConfiguration, ProblemSolving};
 
 #[test]
 fn test_swap_mutation(){
@@ -87,4 +89,105 @@ fn test_mutation_aga_probability_under_avg(){
 
     //We verify the result of the aga mutation probability
     assert_eq!(aga_mutation_probability, probability_max);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_mutation_srinivas_patnaik_probability(){
+    let minimization = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+
+    //In minimization problems, the best fitness is 10 and the average one is 50, so the scores are -10 and -50
+    let parent_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 30.0, age: 0};
+    let parent_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 80.0, age: 0};
+    assert_eq!(srinivas_patnaik_probability(&parent_1, &parent_2, -10.0, -50.0, 0.5, 0.01, &minimization), 0.25);
+
+    //The best individual keeps probability_min and the ones under the average get probability_max
+    let parent_3 = Genotype{dna: Vec::<Gene>::new(), fitness: 10.0, age: 0};
+    assert_eq!(srinivas_patnaik_probability(&parent_2, &parent_3, -10.0, -50.0, 0.5, 0.01, &minimization), 0.01);
+    assert_eq!(srinivas_patnaik_probability(&parent_2, &parent_2, -10.0, -50.0, 0.5, 0.01, &minimization), 0.5);
+}
+
+fn real_dna(len: usize) -> Vec<RealGene>{
+    (0..len).map(|i| RealGene{id: i as i32, value: i as f64}).collect()
+}
+
+#[test]
+fn test_gaussian_mutation(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![], fitness: 0.0, age: 0};
+    let individual_copy = individual.clone();
+
+    gaussian::gaussian(&mut individual, 0.5);
+
+    //The values change but the ids are kept
+    assert_ne!(individual.dna, individual_copy.dna);
+    for (gene, original_gene) in individual.dna.iter().zip(individual_copy.dna.iter()){
+        assert_eq!(gene.id, original_gene.id);
+    }
+}
+
+#[test]
+fn test_creep_mutation(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![], fitness: 0.0, age: 0};
+    let individual_copy = individual.clone();
+
+    creep::creep(&mut individual, 0.5);
+
+    //Every gene stays within the step size
+    assert_ne!(individual.dna, individual_copy.dna);
+    for (gene, original_gene) in individual.dna.iter().zip(individual_copy.dna.iter()){
+        assert!((gene.value - original_gene.value).abs() <= 0.5);
+    }
+}
+
+#[test]
+fn test_log_normal_self_adaptation(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![1.0; 10], fitness: 0.0, age: 0};
+
+    self_adaptive::log_normal(&mut individual);
+
+    //The step sizes change but are always positive
+    assert_eq!(individual.strategy_parameters.len(), 10);
+    assert_ne!(individual.strategy_parameters, vec![1.0; 10]);
+    assert!(individual.strategy_parameters.iter().all(|step_size| *step_size >= self_adaptive::MIN_STEP_SIZE));
+}
+
+#[test]
+fn test_self_adaptive_mutation_factory(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![1e-12], fitness: 0.0, age: 0};
+    let individual_copy = individual.clone();
+
+    mutation::factory_with_configuration(&mut individual, MutationConfiguration{method: Mutation::Gaussian, ..Default::default()});
+
+    //The strategy parameter is mutated and bounded, and the genes are mutated with it instead of the configured step size
+    assert_ne!(individual.strategy_parameters, individual_copy.strategy_parameters);
+    assert!(individual.strategy_parameters[0] >= self_adaptive::MIN_STEP_SIZE);
+    for (gene, original_gene) in individual.dna.iter().zip(individual_copy.dna.iter()){
+        assert!((gene.value - original_gene.value).abs() < 1e-3);
+    }
+}
+
+#[test]
+#[should_panic(expected = "The strategy parameters must have one step size or one step size per gene.")]
+fn test_step_sizes_length(){
+    let individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![1.0, 1.0], fitness: 0.0, age: 0};
+    mutation::step_sizes(&individual, 1.0);
+}
+#[test]
+fn test_random_resetting_mutation(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![], fitness: 0.0, age: 0};
+    random_resetting::random_resetting(&mut individual, &[Domain::Real(100.0, 101.0)]);
+
+    //Only one gene is reset, to a value of the domain
+    let reset: Vec<&RealGene> = individual.dna.iter().filter(|gene| gene.value >= 100.0).collect();
+    assert_eq!(reset.len(), 1);
+    assert!(reset[0].value <= 101.0);
+    assert_eq!(individual.dna.iter().filter(|gene| gene.value == gene.id as f64).count(), 9);
+}
+
+#[test]
+fn test_mutation_factory_with_domains(){
+    let mut individual = SelfAdaptiveGenotype{dna: real_dna(10), strategy_parameters: vec![], fitness: 0.0, age: 0};
+    mutation::factory_with_domains(&mut individual, MutationConfiguration{method: Mutation::Gaussian, step_size: Some(50.0), ..Default::default()}, &[Domain::Real(-1.0, 1.0)]);
+
+    //The mutated values are clamped to the domain
+    assert!(individual.dna.iter().all(|gene| (-1.0..=1.0).contains(&gene.value)));
+}
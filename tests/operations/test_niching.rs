@@ -0,0 +1,82 @@
+#[cfg(test)]
+use crate::structures::{Gene, Genotype};
+use genetic_algorithms::{operations::{niching::{self, sharing, clearing}, Niching}, traits::GenotypeT, configuration::{ProblemSolving, LimitConfiguration, NichingConfiguration}};
+
+fn population(fitness: [f64; 3]) -> Vec<Genotype> {
+    //The two first individuals are in the same niche and the third one is far from them
+    vec![Genotype{dna: vec![Gene{id:1}, Gene{id:2}, Gene{id:3}], fitness: fitness[0], age: 0},
+    Genotype{dna: vec![Gene{id:1}, Gene{id:2}, Gene{id:3}], fitness: fitness[1], age: 0},
+    Genotype{dna: vec![Gene{id:3}, Gene{id:1}, Gene{id:2}], fitness: fitness[2], age: 0}]
+}
+
+#[test]
+fn test_fitness_sharing(){
+
+    //In a maximization problem, the fitness is divided by the niche count
+    let mut individuals = population([10.0, 10.0, 10.0]);
+    sharing::fitness_sharing(&mut individuals, 1.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![5.0, 5.0, 10.0]);
+
+    //In a minimization problem, the fitness is multiplied by the niche count
+    let mut individuals = population([10.0, 10.0, 10.0]);
+    sharing::fitness_sharing(&mut individuals, 1.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![20.0, 20.0, 10.0]);
+
+    //In a fixed fitness problem, the distance to the target is multiplied by the niche count
+    let mut individuals = population([9.0, 11.0, 9.0]);
+    sharing::fitness_sharing(&mut individuals, 1.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::FixedFitness, fitness_target: Some(10.0), ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![8.0, 12.0, 9.0]);
+}
+
+#[test]
+fn test_fitness_sharing_with_negative_fitness(){
+
+    //The fitness is shared from the lowest fitness, so the crowded individuals get worse in maximization problems
+    let mut individuals = population([-2.0, -2.0, -6.0]);
+    sharing::fitness_sharing(&mut individuals, 1.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![-4.0, -4.0, -6.0]);
+
+    //And in minimization problems
+    let mut individuals = population([-2.0, -2.0, -6.0]);
+    sharing::fitness_sharing(&mut individuals, 1.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![2.0, 2.0, -6.0]);
+}
+
+#[test]
+fn test_fitness_sharing_radius(){
+
+    //With a sharing radius greater than the distance between niches, the far individual shares its fitness too
+    let mut individuals = population([12.0, 12.0, 12.0]);
+    sharing::fitness_sharing(&mut individuals, 4.0, 1.0, &LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+
+    //The distance between niches is 3, so the sharing function between them is 0.25
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![12.0 / 2.25, 12.0 / 2.25, 12.0 / 1.5]);
+}
+
+#[test]
+fn test_clearing(){
+
+    //With a niche capacity of 1, only the best individual of each niche keeps its fitness
+    let mut individuals = population([10.0, 8.0, 6.0]);
+    clearing::clearing(&mut individuals, 1.0, 1, &LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![10.0, 6.0, 6.0]);
+
+    //In a minimization problem, the cleared individuals get the highest fitness
+    let mut individuals = population([10.0, 8.0, 12.0]);
+    clearing::clearing(&mut individuals, 1.0, 1, &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![12.0, 8.0, 12.0]);
+
+    //With a niche capacity of 2, every individual keeps its fitness
+    let mut individuals = population([10.0, 8.0, 6.0]);
+    niching::factory(Niching::Clearing, &mut individuals, &NichingConfiguration{niche_capacity: 2, ..Default::default()}, &LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![10.0, 8.0, 6.0]);
+}
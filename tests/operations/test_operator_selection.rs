@@ -0,0 +1,112 @@
+#[cfg(test)]
+use crate::structures::{Gene, Genotype};
+use genetic_algorithms::{rng, operations::{OperatorSelection, operator_selection::{OperatorSelector, improvement, probability_matching::probability_matching, adaptive_pursuit::adaptive_pursuit, upper_confidence_bound::upper_confidence_bound}},
+                         configuration::{OperatorSelectionConfiguration, LimitConfiguration, ProblemSolving}};
+
+#[test]
+fn test_probability_matching(){
+    let probabilities = probability_matching(&[0.0, 1.0, 3.0], 0.1);
+
+    //Each operator keeps the minimum probability and the rest is shared by quality
+    assert!((probabilities[0] - 0.1).abs() < 1e-9);
+    assert!((probabilities[1] - 0.275).abs() < 1e-9);
+    assert!((probabilities[2] - 0.625).abs() < 1e-9);
+    assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_probability_matching_without_quality(){
+    let probabilities = probability_matching(&[0.0, 0.0], 0.1);
+    assert_eq!(probabilities, vec![0.5, 0.5]);
+}
+
+#[test]
+fn test_adaptive_pursuit(){
+    let probabilities = adaptive_pursuit(&[0.2, 0.8, 0.1], &[1.0/3.0, 1.0/3.0, 1.0/3.0], 0.1, 0.5);
+
+    //The best operator goes towards 0.8 and the rest towards 0.1
+    assert!(probabilities[1] > probabilities[0]);
+    assert!((probabilities[1] - (1.0/3.0 + 0.5 * (0.8 - 1.0/3.0))).abs() < 1e-9);
+    assert!((probabilities[0] - (1.0/3.0 + 0.5 * (0.1 - 1.0/3.0))).abs() < 1e-9);
+    assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_upper_confidence_bound(){
+    //Operators never applied are selected first
+    assert_eq!(upper_confidence_bound(&[0.9, 0.0], &[10, 0], 1.0), 1);
+
+    //With the same applications, the best quality wins
+    assert_eq!(upper_confidence_bound(&[0.2, 0.9], &[10, 10], 1.0), 1);
+}
+
+#[test]
+fn test_operator_selector_update(){
+    let configuration = OperatorSelectionConfiguration{..Default::default()};
+    let mut selector = OperatorSelector::new(2, OperatorSelection::ProbabilityMatching, &configuration);
+
+    //Only the second operator produces improvements
+    for _ in 0..20 {
+        selector.update(&[(0, 0.0), (1, 5.0), (1, 10.0)]);
+    }
+
+    assert!(selector.probabilities[1] > selector.probabilities[0]);
+    assert!(selector.probabilities[0] >= configuration.probability_min - 1e-9);
+    assert_eq!(selector.applications, vec![20, 40]);
+
+    let usage = selector.usage(&["first", "second"], &[(0, 0.0), (1, 5.0), (1, 10.0)]);
+    assert_eq!(usage[0].applications, 1);
+    assert_eq!(usage[1].applications, 2);
+    assert_eq!(usage[1].operator, "second");
+}
+
+#[test]
+fn test_operator_selector_select(){
+    let configuration = OperatorSelectionConfiguration{..Default::default()};
+    let mut selector = OperatorSelector::new(3, OperatorSelection::AdaptivePursuit, &configuration);
+
+    //The selections are pending applications, the applications are counted once in the update
+    let selected: Vec<usize> = (0..10).map(|_| selector.select()).collect();
+    assert!(selected.iter().all(|operator| *operator < 3));
+    assert_eq!(selector.applications, vec![0, 0, 0]);
+    assert_eq!(selector.pending_applications.iter().sum::<usize>(), 10);
+    selector.update(&[(0, 1.0), (2, 0.0)]);
+    assert_eq!(selector.applications, vec![1, 0, 1]);
+    assert_eq!(selector.pending_applications, vec![0, 0, 0]);
+
+    //The selection is reproducible with a seed
+    let selections = |selector: &OperatorSelector| {
+        rng::set_seed(Some(5));
+        let mut selector = selector.clone();
+        (0..20).map(|_| selector.select()).collect::<Vec<usize>>()
+    };
+    let (first, second) = (selections(&selector), selections(&selector));
+    rng::set_seed(None);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_upper_confidence_bound_within_a_generation(){
+    //The operators selected in the generation count for the upper confidence bound, so every operator is tried before the update
+    let configuration = OperatorSelectionConfiguration{..Default::default()};
+    let mut selector = OperatorSelector::new(3, OperatorSelection::UpperConfidenceBound, &configuration);
+    let mut selected: Vec<usize> = (0..3).map(|_| selector.select()).collect();
+    selected.sort_unstable();
+    assert_eq!(selected, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_improvement(){
+    let parent_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 10.0, age: 0};
+    let parent_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 20.0, age: 0};
+    let child = Genotype{dna: Vec::<Gene>::new(), fitness: 15.0, age: 0};
+
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+    let minimization = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+    let fixed_fitness = LimitConfiguration{problem_solving: ProblemSolving::FixedFitness, fitness_target: Some(16.0), ..Default::default()};
+
+    assert_eq!(improvement(&parent_1, &parent_2, &child, &maximization), 0.0);
+    assert_eq!(improvement(&parent_1, &parent_2, &Genotype{fitness: 25.0, ..child.clone()}, &maximization), 5.0);
+    assert_eq!(improvement(&parent_1, &parent_2, &Genotype{fitness: 4.0, ..child.clone()}, &minimization), 6.0);
+    assert_eq!(improvement(&parent_1, &parent_2, &child, &fixed_fitness), 3.0);
+}
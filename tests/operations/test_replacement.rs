@@ -0,0 +1,71 @@
+#[cfg(test)]
+use crate::structures::{Gene, Genotype};
+use genetic_algorithms::{operations::{replacement, Replacement}, traits::GenotypeT, configuration::{ProblemSolving, LimitConfiguration}};
+
+fn population() -> Vec<Genotype> {
+    vec![Genotype{dna: vec![Gene{id:1}], fitness: 10.0, age: 3},
+    Genotype{dna: vec![Gene{id:2}], fitness: 20.0, age: 1},
+    Genotype{dna: vec![Gene{id:3}], fitness: 30.0, age: 2},
+    Genotype{dna: vec![Gene{id:4}], fitness: 40.0, age: 4}]
+}
+
+#[test]
+fn test_replacement_worst(){
+    let child = Genotype{dna: vec![Gene{id:5}], fitness: 25.0, age: 5};
+
+    //In a minimization problem, the individual with the highest fitness is replaced
+    let mut individuals = population();
+    replacement::factory(Replacement::Worst, &mut individuals, child.clone(), (0, 1), LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+    assert_eq!(individuals.len(), 4);
+    assert_eq!(individuals[3].get_fitness(), 25.0);
+
+    //In a maximization problem, the individual with the lowest fitness is replaced
+    let mut individuals = population();
+    replacement::factory(Replacement::Worst, &mut individuals, child.clone(), (0, 1), LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    assert_eq!(individuals[0].get_fitness(), 25.0);
+
+    //In a fixed fitness problem, the individual farthest from the target is replaced
+    let mut individuals = population();
+    replacement::factory(Replacement::Worst, &mut individuals, child, (0, 1), LimitConfiguration{problem_solving: ProblemSolving::FixedFitness, fitness_target: Some(32.0), ..Default::default()});
+    assert_eq!(individuals[0].get_fitness(), 25.0);
+}
+
+#[test]
+fn test_replacement_random(){
+    let child = Genotype{dna: vec![Gene{id:5}], fitness: 25.0, age: 5};
+    let mut individuals = population();
+    replacement::factory(Replacement::Random, &mut individuals, child, (0, 1), LimitConfiguration{..Default::default()});
+
+    //Exactly one individual has been replaced by the child
+    assert_eq!(individuals.len(), 4);
+    assert_eq!(individuals.iter().filter(|individual| individual.get_fitness() == 25.0).count(), 1);
+}
+
+#[test]
+fn test_replacement_oldest(){
+    let child = Genotype{dna: vec![Gene{id:5}], fitness: 25.0, age: 5};
+    let mut individuals = population();
+    replacement::factory(Replacement::Oldest, &mut individuals, child, (0, 1), LimitConfiguration{..Default::default()});
+
+    //The individual born in the earliest generation is replaced
+    assert_eq!(individuals.len(), 4);
+    assert_eq!(individuals[1].get_fitness(), 25.0);
+    assert_eq!(individuals[1].get_age(), 5);
+}
+
+#[test]
+fn test_replacement_parent_if_better(){
+    let limit_configuration = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+
+    //The child replaces the worst of its parents when it is better
+    let mut individuals = population();
+    replacement::factory(Replacement::ParentIfBetter, &mut individuals, Genotype{dna: vec![Gene{id:5}], fitness: 25.0, age: 5}, (1, 2), limit_configuration);
+    assert_eq!(individuals[1].get_fitness(), 20.0);
+    assert_eq!(individuals[2].get_fitness(), 25.0);
+
+    //The child is discarded when it is worse than both parents
+    let mut individuals = population();
+    replacement::factory(Replacement::ParentIfBetter, &mut individuals, Genotype{dna: vec![Gene{id:5}], fitness: 35.0, age: 5}, (1, 2), limit_configuration);
+    let fitness: Vec<f64> = individuals.iter().map(|individual| individual.get_fitness()).collect();
+    assert_eq!(fitness, vec![10.0, 20.0, 30.0, 40.0]);
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 use crate::structures::{Gene, Genotype};
-use genetic_algorithms::{operations::survivor::{fitness, age}, traits::GenotypeT, configuration::{ProblemSolving, LimitConfiguration}};
+use genetic_algorithms::{operations::survivor::{fitness, age, crowding, restricted_tournament}, traits::GenotypeT, configuration::{ProblemSolving, LimitConfiguration}};
 
 #[test]
 fn test_fitness_survivor_minization(){
@@ -42,8 +42,8 @@ fn test_fitness_survivor_minization(){
 
     //Tests that the population has 10 individuals
     assert_eq!(population.len(), 10);
-    assert_eq!(population[0].get_fitness(), 17.0);
-    assert_eq!(population[9].get_fitness(), 10.1);
+    assert_eq!(population[0].get_fitness(), 10.1);
+    assert_eq!(population[9].get_fitness(), 17.0);
 
 }
 
@@ -184,7 +184,76 @@ fn test_survivor_fitness_fixed(){
 
     //Tests that the population has 10 individuals
     assert_eq!(population.len(), 10);
-    assert_eq!(population[0].get_fitness(), 10.2);
-    assert_eq!(population[9].get_fitness(), 15.0);
+    assert_eq!(population[0].get_fitness_distance(&14.5), 0.5);
+    assert_eq!(population[9].get_fitness(), 10.2);
 
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_fitness_survivor_nan(){
+
+    //Individuals with a NaN fitness are always the first ones to be removed
+    for problem_solving in [ProblemSolving::Minimization, ProblemSolving::Maximization] {
+        let mut population = vec![Genotype{dna: vec![Gene{id:1}], fitness: f64::NAN, age: 0},
+        Genotype{dna: vec![Gene{id:1}], fitness: 2.0, age: 0},
+        Genotype{dna: vec![Gene{id:1}], fitness: f64::NAN, age: 0},
+        Genotype{dna: vec![Gene{id:1}], fitness: 1.0, age: 0},
+        Genotype{dna: vec![Gene{id:1}], fitness: 3.0, age: 0}];
+
+        fitness::fitness_based(&mut population, 3, LimitConfiguration{problem_solving, ..Default::default()});
+
+        let fitness: Vec<f64> = population.iter().map(|individual| individual.get_fitness()).collect();
+        match problem_solving {
+            ProblemSolving::Minimization => assert_eq!(fitness, vec![1.0, 2.0, 3.0]),
+            _ => assert_eq!(fitness, vec![3.0, 2.0, 1.0]),
+        }
+    }
+}
+
+#[test]
+fn test_genotype_distance(){
+
+    //By default, the distance is the Hamming distance over the gene ids
+    let individual_1 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4}], fitness: 0.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}, Gene{id:3}, Gene{id:2}, Gene{id:4}], fitness: 0.0, age: 0};
+    assert_eq!(individual_1.get_distance(&individual_1), 0.0);
+    assert_eq!(individual_1.get_distance(&individual_2), 2.0);
+}
+
+#[test]
+fn test_deterministic_crowding(){
+
+    //Two parents in different niches
+    let mut population = vec![Genotype{dna: vec![Gene{id:1}, Gene{id:1}, Gene{id:1}], fitness: 5.0, age: 0},
+    Genotype{dna: vec![Gene{id:9}, Gene{id:9}, Gene{id:9}], fitness: 5.0, age: 0}];
+
+    //The first child is similar to the second parent and the second child to the first parent
+    let offspring = vec![Genotype{dna: vec![Gene{id:9}, Gene{id:9}, Gene{id:8}], fitness: 4.0, age: 1},
+    Genotype{dna: vec![Gene{id:1}, Gene{id:1}, Gene{id:2}], fitness: 6.0, age: 1}];
+
+    crowding::deterministic_crowding(&mut population, offspring, &[(0, 1), (0, 1)], &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+
+    //Only the first child beats its closest parent, and it replaces the second parent
+    assert_eq!(population.len(), 2);
+    assert_eq!(population[0].get_fitness(), 5.0);
+    assert_eq!(population[0].dna[2].id, 1);
+    assert_eq!(population[1].get_fitness(), 4.0);
+    assert_eq!(population[1].dna[2].id, 8);
+}
+
+#[test]
+fn test_restricted_tournament(){
+
+    //Two niches in the population
+    let mut population = vec![Genotype{dna: vec![Gene{id:1}, Gene{id:1}, Gene{id:1}], fitness: 5.0, age: 0},
+    Genotype{dna: vec![Gene{id:9}, Gene{id:9}, Gene{id:9}], fitness: 1.0, age: 0}];
+
+    //The window covers the whole population, so each child competes with the most similar individual
+    let offspring = vec![Genotype{dna: vec![Gene{id:1}, Gene{id:1}, Gene{id:2}], fitness: 3.0, age: 1},
+    Genotype{dna: vec![Gene{id:9}, Gene{id:9}, Gene{id:8}], fitness: 2.0, age: 1}];
+    restricted_tournament::restricted_tournament(&mut population, offspring, 2, &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+
+    //The first child replaces the first individual and the second child is discarded, although it's better than the first individual
+    assert_eq!(population[0].get_fitness(), 3.0);
+    assert_eq!(population[1].get_fitness(), 1.0);
+}
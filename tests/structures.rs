@@ -1,7 +1,11 @@
+//Not every test crate uses every structure
+#![allow(dead_code)]
+
 use genetic_algorithms::traits::{GeneT, GenotypeT};
 
 //Structures definition
 #[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gene{
     pub id: i32,
 }
@@ -16,6 +20,7 @@ impl GeneT for Gene{
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Genotype{
     pub dna: Vec<Gene>,
     pub fitness: f64,
@@ -53,4 +58,77 @@ impl GenotypeT for Genotype{
         self.dna = dna.to_vec();
         self
     }
-}
\ No newline at end of file
+}
+
+//Numeric gene and self-adaptive genotype, minimizing the sum of squares of the values
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct RealGene{
+    pub id: i32,
+    pub value: f64,
+}
+impl GeneT for RealGene{
+    fn get_id(&self) -> i32{
+        self.id
+    }
+    fn set_id(&mut self, id: i32)->&mut Self {
+        self.id = id;
+        self
+    }
+    fn get_value(&self) -> f64 {
+        self.value
+    }
+    fn set_value(&mut self, value: f64) -> &mut Self {
+        self.value = value;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfAdaptiveGenotype{
+    pub dna: Vec<RealGene>,
+    pub strategy_parameters: Vec<f64>,
+    pub fitness: f64,
+    pub age: i32,
+}
+impl Default for SelfAdaptiveGenotype{
+    fn default() -> Self {
+        SelfAdaptiveGenotype { dna: Vec::new(), strategy_parameters: vec![1.0], fitness: 0.0, age: 0 }
+    }
+}
+impl GenotypeT for SelfAdaptiveGenotype{
+    type Gene = RealGene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64)->&mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age:i32)->&mut Self{
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+    fn calculate_fitness(&mut self) {
+        self.fitness = self.dna.iter().map(|gene| gene.value * gene.value).sum();
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene])->&mut Self{
+        self.dna = dna.to_vec();
+        self
+    }
+    fn get_strategy_parameters(&self) -> &[f64] {
+        &self.strategy_parameters
+    }
+    fn set_strategy_parameters(&mut self, strategy_parameters: &[f64]) -> &mut Self {
+        self.strategy_parameters = strategy_parameters.to_vec();
+        self
+    }
+    fn get_distance(&self, other: &Self) -> f64 {
+        self.dna.iter().zip(&other.dna).map(|(gene_1, gene_2)| (gene_1.value - gene_2.value).powi(2)).sum::<f64>().sqrt()
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod structures;
+use std::sync::Mutex;
+use genetic_algorithms::{cache::{self, FitnessCache, dna_key}, configuration::ProblemSolving, domain::Domain, ga::Ga, operations::{Crossover, Mutation, Selection, Survivor}, problems::{Problem, RealGene, continuous::{Continuous, Sphere}}, rng, traits::{ConfigurationT, GenotypeT}};
+use crate::structures::{Gene, Genotype};
+
+fn dna(ids: &[i32]) -> Vec<Gene> {
+    ids.iter().map(|id| Gene{id: *id}).collect()
+}
+
+#[test]
+fn test_least_recently_used(){
+    let mut fitness_cache = FitnessCache::new(2);
+    fitness_cache.insert(1, 10.0);
+    fitness_cache.insert(2, 20.0);
+    assert_eq!(fitness_cache.get(&1), Some(10.0));
+
+    //The key 2 is the least recently used, so it's discarded
+    fitness_cache.insert(3, 30.0);
+    assert_eq!(fitness_cache.len(), 2);
+    assert_eq!(fitness_cache.get(&2), None);
+    assert_eq!(fitness_cache.get(&1), Some(10.0));
+    assert_eq!(fitness_cache.get(&3), Some(30.0));
+
+    //Updating a key doesn't discard any other key
+    fitness_cache.insert(3, 35.0);
+    assert_eq!(fitness_cache.len(), 2);
+    assert_eq!(fitness_cache.get(&3), Some(35.0));
+    assert_eq!(fitness_cache.get(&1), Some(10.0));
+
+    let statistics = fitness_cache.statistics();
+    assert_eq!((statistics.hits, statistics.misses), (5, 1));
+    assert_eq!(statistics.hit_rate, 5.0 / 6.0);
+}
+
+#[test]
+fn test_dna_key(){
+    assert_eq!(dna_key::<Genotype>(&dna(&[1, 2, 3])), dna_key::<Genotype>(&dna(&[1, 2, 3])));
+    assert_ne!(dna_key::<Genotype>(&dna(&[1, 2, 3])), dna_key::<Genotype>(&dna(&[3, 2, 1])));
+    assert_ne!(dna_key::<Genotype>(&dna(&[1, 2])), dna_key::<Genotype>(&dna(&[1, 2, 0])));
+}
+
+#[test]
+fn test_dna_key_with_values(){
+    //The genes with the same ids and different values have different keys
+    let genes = |values: [f64; 2]| -> Vec<RealGene> {values.iter().map(|value| RealGene{id: 0, value: *value}).collect()};
+    assert_eq!(dna_key::<Continuous<Sphere>>(&genes([1.5, -2.0])), dna_key::<Continuous<Sphere>>(&genes([1.5, -2.0])));
+    assert_ne!(dna_key::<Continuous<Sphere>>(&genes([1.5, -2.0])), dna_key::<Continuous<Sphere>>(&genes([1.5, -2.5])));
+}
+
+#[test]
+fn test_cached_fitness(){
+    let fitness_cache = Mutex::new(FitnessCache::new(10));
+    let mut individual = Genotype{dna: dna(&[3, 2, 1]), fitness: 0.0, age: 0};
+    assert!(cache::calculate_fitness(&mut individual, Some(&fitness_cache)));
+    assert_eq!(individual.fitness, 4.0);
+
+    //The fitness of the same dna is taken from the cache
+    let mut copy = Genotype{dna: dna(&[3, 2, 1]), fitness: 0.0, age: 0};
+    assert!(!cache::calculate_fitness(&mut copy, Some(&fitness_cache)));
+    assert_eq!(copy.fitness, 4.0);
+    let statistics = fitness_cache.lock().unwrap().statistics();
+    assert_eq!((statistics.hits, statistics.misses), (1, 1));
+}
+
+fn permutations() -> Ga<Genotype> {
+    let mut ga = Ga::<Genotype>::new();
+    ga.with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Cycle)
+        .with_crossover_probability_max(0.5)
+        .with_mutation_method(Mutation::Swap)
+        .with_mutation_probability_max(0.2)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(4)
+        .with_max_generations(20);
+    ga.with_alleles(dna(&[1, 2, 3, 4]));
+    ga
+}
+
+#[test]
+fn test_run_with_fitness_cache(){
+    let mut ga = permutations();
+    ga.with_fitness_cache(50);
+    let population = ga.run();
+
+    //The children equal to their parents are found in the cache
+    let statistics: Vec<_> = population.statistics.iter().map(|generation| generation.cache.unwrap()).collect();
+    let last = statistics.last().unwrap();
+    assert!(last.hits > 0);
+    assert_eq!(last.hit_rate, last.hits as f64 / (last.hits + last.misses) as f64);
+    assert!(statistics.windows(2).all(|pair| pair[0].hits + pair[0].misses <= pair[1].hits + pair[1].misses));
+    for individual in &ga.population.individuals {
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.get_fitness(), expected.get_fitness());
+    }
+
+    //Without the cache, there are no cache statistics
+    let population = permutations().run();
+    assert!(population.statistics.iter().all(|generation| generation.cache.is_none()));
+}
+
+fn sphere() -> Ga<Continuous<Sphere>> {
+    let mut ga = Ga::<Continuous<Sphere>>::new();
+    ga.with_problem_solving(Continuous::<Sphere>::problem_solving())
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(10)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Gaussian)
+        .with_mutation_probability_max(0.1)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(20)
+        .with_genes_per_individual(5)
+        .with_alleles_can_be_repeated(true)
+        .with_fitness_cache(100)
+        .with_max_generations(20);
+    ga.with_domains(vec![Domain::Real(-5.12, 5.12)]);
+    ga
+}
+
+#[test]
+fn test_run_with_fitness_cache_and_real_values(){
+    //The genes of the continuous problems have the same ids, so their fitness is only found in the cache with the same values
+    let mut ga = sphere();
+    rng::set_seed(Some(3));
+    let population = ga.run();
+    rng::set_seed(None);
+
+    for individual in &ga.population.individuals {
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.get_fitness(), expected.get_fitness());
+    }
+
+    //The initial population is in the cache, so the crossed children equal to their parents are found in it
+    assert!(population.statistics.last().unwrap().cache.unwrap().hits > 0);
+}
+
+#[test]
+fn test_run_with_fitness_cache_and_unique_ids(){
+    //The ids of the genes are the loci, and the genes are told apart by their values
+    let mut ga = sphere();
+    ga.with_needs_unique_ids(true);
+    ga.run();
+    for individual in &ga.population.individuals {
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.get_fitness(), expected.get_fitness());
+        assert!(individual.get_dna().iter().enumerate().all(|(locus, gene)| gene.id == locus as i32));
+    }
+}
+
+#[test]
+#[should_panic(expected = "The fitness cache is keyed by the ids and the values of the genes, and the values of these genes follow their ids, so it can't be used when the genes need unique ids.")]
+fn test_fitness_cache_with_unique_ids(){
+    let mut ga = permutations();
+    ga.with_needs_unique_ids(true).with_alleles_can_be_repeated(true).with_fitness_cache(50);
+    ga.run();
+}
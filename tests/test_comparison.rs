@@ -0,0 +1,91 @@
+mod structures;
+use genetic_algorithms::{comparison::{self, Comparison, Sample}, configuration::{LimitConfiguration, ProblemSolving}, experiment::Experiment, operations::{Selection, Crossover, Mutation}, traits::ConfigurationT, configuration::GaConfiguration};
+use structures::{Gene, Genotype};
+
+#[test]
+fn test_mann_whitney_u(){
+    //Samples without overlap
+    let result = comparison::mann_whitney_u(&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0, 8.0, 9.0, 10.0]);
+    assert_eq!(result.u, 0.0);
+    assert!((result.z + 2.5067182).abs() < 1e-6);
+    assert!((result.p_value - 0.0121858).abs() < 1e-6);
+
+    //The same samples are not different, even with ties
+    assert_eq!(comparison::mann_whitney_u(&[1.0, 2.0, 2.0, 3.0], &[1.0, 2.0, 2.0, 3.0]).p_value, 1.0);
+    assert_eq!(comparison::mann_whitney_u(&[1.0, 1.0, 1.0], &[1.0, 1.0, 1.0]).p_value, 1.0);
+}
+
+#[test]
+fn test_a12(){
+    assert_eq!(comparison::a12(&[3.0, 4.0], &[1.0, 2.0]), 1.0);
+    assert_eq!(comparison::a12(&[1.0, 2.0], &[3.0, 4.0]), 0.0);
+    assert_eq!(comparison::a12(&[1.0, 2.0, 3.0], &[2.0, 2.0]), 0.5);
+    assert_eq!(comparison::a12(&[2.0, 3.0], &[1.0, 3.0]), 0.625);
+}
+
+#[test]
+fn test_friedman_and_nemenyi(){
+    let result = comparison::friedman(&[vec![9.0, 8.0, 7.0, 9.0], vec![5.0, 6.0, 4.0, 5.0], vec![1.0, 2.0, 3.0, 1.0]]);
+    assert_eq!(result.mean_ranks, vec![1.0, 2.0, 3.0]);
+    assert!((result.statistic - 8.0).abs() < 1e-12);
+    assert!((result.p_value - (-4.0_f64).exp()).abs() < 1e-9);
+
+    //Every configuration tied
+    let result = comparison::friedman(&[vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0]]);
+    assert_eq!(result.mean_ranks, vec![2.0, 2.0, 2.0]);
+    assert_eq!(result.p_value, 1.0);
+
+    assert!((comparison::nemenyi_critical_difference(3, 4).unwrap() - 2.343 * 0.5_f64.sqrt()).abs() < 1e-12);
+    assert!(comparison::nemenyi_critical_difference(11, 4).is_none());
+}
+
+#[test]
+fn test_comparison_report(){
+    let samples = vec![Sample::new("A", vec![1.0, 2.0, 3.0, 1.5, 2.5]), Sample::new("B", vec![7.0, 8.0, 9.0, 8.5, 7.5]), Sample::new("C", vec![4.0, 5.0, 6.0, 5.5, 4.5])];
+    let comparison = Comparison::new(samples, &LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+
+    //In minimization, the lower fitness is better
+    assert_eq!(comparison.pairwise.len(), 3);
+    assert_eq!((comparison.pairwise[0].first, comparison.pairwise[0].second, comparison.pairwise[0].a12), (0, 1, 1.0));
+    assert!(comparison.pairwise[0].mann_whitney.p_value < comparison::SIGNIFICANCE_LEVEL);
+    assert_eq!(comparison.friedman.as_ref().unwrap().mean_ranks, vec![1.0, 3.0, 2.0]);
+    assert_eq!(comparison.summaries[0].median, 2.0);
+
+    let report = comparison.to_markdown();
+    assert!(report.contains("| A | 5 | 2.000000 | 2.000000 |"));
+    assert!(report.contains("| A | B | 25 |"));
+    assert!(report.contains("| Large | A is better |"));
+    assert!(report.contains("## Friedman test"));
+    assert!(report.contains("Nemenyi post-hoc test"));
+    assert!(report.contains("| A | B | 2.000 | Significant |"));
+    assert_eq!(comparison.to_string(), report);
+}
+
+#[test]
+fn test_comparison_of_experiments(){
+    let mut configuration = GaConfiguration::new();
+    configuration.with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Swap)
+        .with_population_size(10)
+        .with_genes_per_individual(6)
+        .with_alleles_can_be_repeated(true);
+    let results = Experiment::<Genotype>::new(configuration.clone())
+        .with_alleles((1..9).map(|id| Gene{id}).collect())
+        .with_runs(5)
+        .with_grid(vec![vec![genetic_algorithms::experiment::Parameter::new("1 generation", |configuration| {configuration.with_max_generations(1);}),
+                             genetic_algorithms::experiment::Parameter::new("30 generations", |configuration| {configuration.with_max_generations(30);})]])
+        .run();
+
+    //The samples have the best fitness of each run
+    let samples: Vec<Sample> = results.iter().map(Sample::from).collect();
+    assert_eq!(samples[1].name, "30 generations");
+    assert_eq!(samples[1].values, results[1].runs.iter().map(|run| run.best_fitness).collect::<Vec<f64>>());
+
+    //With the same seeds, the longer runs can't be worse
+    let comparison = Comparison::new(samples, &configuration.limit_configuration);
+    assert!(comparison.pairwise[0].a12 <= 0.5);
+    assert!(comparison.friedman.unwrap().mean_ranks[1] <= 1.5);
+}
@@ -0,0 +1,41 @@
+use genetic_algorithms::{configuration::{GaConfiguration, ConfigurationError, ProblemSolving, Schedule}, operations::{Crossover, Survivor, Niching, OperatorSelection}, traits::ConfigurationT};
+
+#[test]
+fn test_validate_configuration(){
+    assert!(GaConfiguration::new().validate().is_ok());
+
+    let mut configuration = GaConfiguration::new();
+    configuration.with_crossover_method(Crossover::MultiPoint).with_crossover_number_of_points(2).with_survivor_method(Survivor::MuCommaLambda).with_lambda(20);
+    assert!(configuration.validate().is_ok());
+
+    //Each invalid value is reported
+    let invalid_configurations = [
+        GaConfiguration::new().with_problem_solving(ProblemSolving::FixedFitness).clone(),
+        GaConfiguration::new().with_crossover_probability_min(-0.1).clone(),
+        GaConfiguration::new().with_adaptive_ga(true).with_crossover_probability_max(0.5).with_crossover_probability_min(0.6).clone(),
+        GaConfiguration::new().with_crossover_method(Crossover::MultiPoint).clone(),
+        GaConfiguration::new().with_mutation_schedule(Schedule::Exponential { rate: 0.0 }).clone(),
+        GaConfiguration::new().with_survivor_method(Survivor::MuCommaLambda).clone(),
+        GaConfiguration::new().with_niching_method(Niching::FitnessSharing).with_sigma_share(0.0).clone(),
+        GaConfiguration::new().with_steady_state(true).with_offspring_per_step(0).clone(),
+        GaConfiguration::new().with_export_history(true).clone(),
+        GaConfiguration::new().with_fitness_cache(0).clone(),
+        GaConfiguration::new().with_operator_selection_method(OperatorSelection::ProbabilityMatching).clone(),
+        GaConfiguration::new().with_population_size(30).with_survivor_method(Survivor::MuCommaLambda).with_lambda(20).clone(),
+    ];
+    for configuration in invalid_configurations {
+        assert!(matches!(configuration.validate(), Err(ConfigurationError::Invalid(_))));
+    }
+
+    //The values of the methods that are not used are not checked, as in the run
+    assert!(GaConfiguration::new().with_sigma_share(0.0).with_offspring_per_step(0).validate().is_ok());
+
+    //The fitness cache with unique ids depends on the genes, so it's checked before the run
+    assert!(GaConfiguration::new().with_fitness_cache(10).with_needs_unique_ids(true).validate().is_ok());
+
+    //The messages are the ones of the condition checkers of the run
+    match GaConfiguration::new().with_survivor_method(Survivor::MuCommaLambda).validate() {
+        Err(ConfigurationError::Invalid(message)) => assert_eq!(message, "For the (mu, lambda) survivor, the lambda must be set."),
+        _ => panic!("The configuration must be invalid"),
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod structures;
+use genetic_algorithms::{distance, configuration::Distance};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
+
+#[test]
+fn test_hamming_distance(){
+    let individual_1 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4}], fitness: 0.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}, Gene{id:3}, Gene{id:2}, Gene{id:4}], fitness: 0.0, age: 0};
+    let individual_3 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}], fitness: 0.0, age: 0};
+
+    assert_eq!(distance::hamming(&individual_1, &individual_1), 0.0);
+    assert_eq!(distance::hamming(&individual_1, &individual_2), 2.0);
+
+    //The missing genes count as different
+    assert_eq!(distance::hamming(&individual_1, &individual_3), 2.0);
+}
+
+#[test]
+fn test_euclidean_distance(){
+    let individual_1 = SelfAdaptiveGenotype{dna: vec![RealGene{id: 0, value: 1.0}, RealGene{id: 1, value: 2.0}], ..Default::default()};
+    let individual_2 = SelfAdaptiveGenotype{dna: vec![RealGene{id: 0, value: 4.0}, RealGene{id: 1, value: 6.0}], ..Default::default()};
+
+    assert_eq!(distance::euclidean(&individual_1, &individual_2), 5.0);
+    assert_eq!(distance::factory(Distance::Euclidean, &individual_1, &individual_2), 5.0);
+}
+
+#[test]
+fn test_kendall_tau_distance(){
+    let individual_1 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4}], fitness: 0.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}, Gene{id:3}, Gene{id:2}, Gene{id:4}], fitness: 0.0, age: 0};
+    let individual_3 = Genotype{dna: vec![Gene{id:4}, Gene{id:3}, Gene{id:2}, Gene{id:1}], fitness: 0.0, age: 0};
+
+    //Swapping two adjacent genes changes the order of one pair, and reversing the permutation changes every pair
+    assert_eq!(distance::kendall_tau(&individual_1, &individual_1), 0.0);
+    assert_eq!(distance::kendall_tau(&individual_1, &individual_2), 1.0);
+    assert_eq!(distance::kendall_tau(&individual_1, &individual_3), 6.0);
+    assert_eq!(distance::factory(Distance::KendallTau, &individual_3, &individual_1), 6.0);
+}
+
+#[test]
+#[should_panic(expected = "The Kendall tau distance needs both genotypes to be permutations of the same genes.")]
+fn test_kendall_tau_distance_not_permutations(){
+    let individual_1 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}], fitness: 0.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}, Gene{id:3}], fitness: 0.0, age: 0};
+    distance::kendall_tau(&individual_1, &individual_2);
+}
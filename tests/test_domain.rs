@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod structures;
+use genetic_algorithms::{domain::{Domain, locus_domain}, ga::Ga, helpers, operations::{Crossover, Mutation, Selection, Survivor}, rng, traits::{ConfigurationT, GenotypeT}};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
+
+fn domains() -> Vec<Domain<Gene>> {
+    vec![Domain::Discrete(vec![Gene{id: 10}, Gene{id: 20}, Gene{id: 30}]), Domain::Integer(1, 64), Domain::Integer(-3, 3)]
+}
+
+fn in_domain(gene: &Gene, domain: &Domain<Gene>) -> bool {
+    match domain {
+        Domain::Discrete(genes) => genes.contains(gene),
+        Domain::Integer(min, max) => (*min..=*max).contains(&(gene.id as i64)),
+        Domain::Real(..) => false,
+    }
+}
+
+#[test]
+fn test_sample_and_repair(){
+    let mut rng = rand::thread_rng();
+    for domain in domains() {
+        for _ in 0..50 {
+            assert!(in_domain(&domain.sample(&mut rng), &domain));
+        }
+    }
+    let real: Domain<RealGene> = Domain::Real(-0.5, 0.5);
+    assert!((0..50).all(|_| real.sample(&mut rng).value.abs() <= 0.5));
+
+    //The values are clamped to the ranges, and the discrete domains take the closest gene
+    let mut gene = Gene{id: 80};
+    Domain::Integer(1, 64).repair(&mut gene);
+    assert_eq!(gene.id, 64);
+    let mut gene = Gene{id: 24};
+    domains()[0].repair(&mut gene);
+    assert_eq!(gene.id, 20);
+    let mut gene = RealGene{id: 3, value: -2.0};
+    real.repair(&mut gene);
+    assert_eq!(gene, RealGene{id: 3, value: -0.5});
+}
+
+#[test]
+fn test_locus_domains(){
+    let domains = domains();
+    assert_eq!(locus_domain(&domains, 1), &Domain::Integer(1, 64));
+    let shared: Vec<Domain<Gene>> = vec![Domain::Integer(0, 1)];
+    assert_eq!(locus_domain(&shared, 7), &Domain::Integer(0, 1));
+
+    assert!(Domain::<Gene>::Discrete(vec![]).is_empty());
+    assert!(Domain::<Gene>::Integer(2, 1).is_empty());
+    assert!(Domain::<Gene>::Real(0.0, f64::NAN).is_empty());
+    assert!(!Domain::<Gene>::Real(1.0, 1.0).is_empty());
+}
+
+#[test]
+fn test_initialize_dna_from_domains(){
+    let domains = domains();
+    for _ in 0..20 {
+        let dna = helpers::initialize_dna_from_domains::<Genotype>(&domains, 3, false);
+        assert_eq!(dna.len(), 3);
+        assert!(dna.iter().enumerate().all(|(locus, gene)| in_domain(gene, &domains[locus])));
+    }
+
+    //With unique ids, the values are kept and the ids are the loci
+    let dna = helpers::initialize_dna_from_domains::<SelfAdaptiveGenotype>(&[Domain::Real(2.0, 3.0)], 4, true);
+    assert_eq!(dna.iter().map(|gene| gene.id).collect::<Vec<i32>>(), vec![0, 1, 2, 3]);
+    assert!(dna.iter().all(|gene| (2.0..=3.0).contains(&gene.value)));
+}
+
+fn ga(mutation: Mutation) -> Ga<Genotype> {
+    let mut ga = Ga::<Genotype>::new();
+    ga.with_problem_solving(genetic_algorithms::configuration::ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(mutation)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(3)
+        .with_max_generations(20);
+    ga
+}
+
+#[test]
+fn test_run_with_domains(){
+    for mutation in [Mutation::RandomResetting, Mutation::Creep, Mutation::Gaussian] {
+        rng::set_seed(Some(11));
+        let mut ga = ga(mutation);
+        ga.with_mutation_step_size(20.0);
+        ga.with_domains(domains());
+        let population = ga.run();
+        rng::set_seed(None);
+
+        //Every gene stays in the domain of its locus, without alleles
+        for individual in &population.individuals {
+            assert!(individual.get_dna().iter().enumerate().all(|(locus, gene)| in_domain(gene, &domains()[locus])), "{:?}", mutation);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The random resetting mutation needs the domains of the loci.")]
+fn test_random_resetting_without_domains(){
+    let mut ga = ga(Mutation::RandomResetting);
+    ga.with_alleles((1..9).map(|id| Gene{id}).collect()).with_alleles_can_be_repeated(true);
+    ga.run();
+}
+
+#[test]
+#[should_panic(expected = "There must be one domain for every locus or one domain per locus.")]
+fn test_domains_length(){
+    let mut ga = ga(Mutation::RandomResetting);
+    ga.with_domains(vec![Domain::Integer(0, 1), Domain::Integer(0, 1)]);
+    ga.run();
+}
+
+#[test]
+#[should_panic(expected = "The domain of the locus 1 has no values.")]
+fn test_empty_domain(){
+    let mut ga = ga(Mutation::RandomResetting);
+    ga.with_domains(vec![Domain::Integer(0, 1), Domain::Discrete(vec![]), Domain::Integer(0, 1)]);
+    ga.run();
+}
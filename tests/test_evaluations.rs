@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod structures;
+use std::cell::Cell;
+use genetic_algorithms::{configuration::{CrossoverConfiguration, ProblemSolving}, ga::Ga, initialization::{Initializer, InitializationContext, random::Random}, operations::{crossover, mutation, Crossover, Mutation, Selection, Survivor}, traits::{ConfigurationT, GenotypeT}};
+use crate::structures::Gene;
+
+thread_local! {
+    //Calls to the fitness function in the current thread
+    static CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+//Genotype that keeps the fitness valid flag and counts the calls to the fitness function. The flag is only reset by the operators
+#[derive(Debug, Clone, Default, PartialEq)]
+struct CountedGenotype{
+    dna: Vec<Gene>,
+    fitness: f64,
+    age: i32,
+    fitness_valid: bool,
+}
+impl GenotypeT for CountedGenotype{
+    type Gene = Gene;
+    fn get_dna(&self) -> &[Self::Gene] {
+        &self.dna
+    }
+    fn get_fitness(&self) -> f64 {
+        self.fitness
+    }
+    fn set_fitness(&mut self, fitness: f64)->&mut Self {
+        self.fitness = fitness;
+        self
+    }
+    fn set_age(&mut self, age: i32)->&mut Self {
+        self.age = age;
+        self
+    }
+    fn get_age(&self) -> i32 {
+        self.age
+    }
+    fn set_dna(&mut self, dna: &[Self::Gene])->&mut Self {
+        self.dna = dna.to_vec();
+        self
+    }
+    fn calculate_fitness(&mut self) {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        self.fitness = self.dna.iter().enumerate().map(|(index, gene)| (gene.id * index as i32) as f64).sum();
+    }
+    fn is_fitness_valid(&self) -> bool {
+        self.fitness_valid
+    }
+    fn set_fitness_valid(&mut self, fitness_valid: bool) -> &mut Self {
+        self.fitness_valid = fitness_valid;
+        self
+    }
+}
+
+fn alleles() -> Vec<Gene> {
+    (1..5).map(|id| Gene{id}).collect()
+}
+
+//The fitness function runs in the current thread, so the calls can be counted
+fn ga(crossover_probability: f64, mutation_probability: f64) -> Ga<CountedGenotype> {
+    let mut ga = Ga::<CountedGenotype>::new();
+    ga.with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Random)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Cycle)
+        .with_crossover_probability_max(crossover_probability)
+        .with_mutation_method(Mutation::Swap)
+        .with_mutation_probability_max(mutation_probability)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(4)
+        .with_threads(1)
+        .with_max_generations(5);
+    ga.with_alleles(alleles());
+    ga
+}
+
+#[test]
+fn test_unchanged_offspring_are_not_evaluated(){
+    CALLS.with(|calls| calls.set(0));
+    let population = ga(0.0, 0.0).run();
+
+    //Only the initial population is evaluated, once. The children are clones of their parents and keep their fitness
+    let last = population.statistics.last().unwrap();
+    assert_eq!(CALLS.with(|calls| calls.get()), 10);
+    assert_eq!(last.evaluations, 10);
+    assert_eq!(last.skipped_evaluations, 5 * 10);
+}
+
+#[test]
+fn test_evaluations_are_counted(){
+    CALLS.with(|calls| calls.set(0));
+    let population = ga(1.0, 1.0).run();
+
+    //Every child is changed, so every child is evaluated
+    let statistics = &population.statistics;
+    assert_eq!(statistics.last().unwrap().evaluations, CALLS.with(|calls| calls.get()));
+    assert_eq!(statistics.last().unwrap().evaluations, 10 + 5 * 10);
+    assert!(statistics.iter().all(|generation| generation.skipped_evaluations == 0));
+    for individual in &population.individuals {
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.fitness, expected.fitness);
+    }
+}
+
+#[test]
+fn test_operators_invalidate_the_fitness(){
+    let mut parent = CountedGenotype{dna: alleles(), ..Default::default()};
+    parent.calculate_fitness();
+    parent.set_fitness_valid(true);
+
+    for method in [Crossover::Cycle, Crossover::Uniform] {
+        let children = crossover::factory(&parent, &parent, CrossoverConfiguration{method, ..Default::default()}).unwrap();
+        assert!(children.iter().all(|child| !child.is_fitness_valid()));
+    }
+    for method in [Mutation::Swap, Mutation::Inversion, Mutation::Scramble] {
+        let mut individual = parent.clone();
+        mutation::factory(method, &mut individual);
+        assert!(!individual.is_fitness_valid());
+    }
+}
+
+#[test]
+fn test_valid_fitness_of_the_population(){
+    //Only the individuals without a valid fitness are evaluated at the start of the run
+    let individuals: Vec<CountedGenotype> = (0..10).map(|index| {
+        let mut individual = CountedGenotype{dna: alleles(), ..Default::default()};
+        individual.calculate_fitness();
+        individual.set_fitness_valid(index % 2 == 0);
+        individual
+    }).collect();
+    CALLS.with(|calls| calls.set(0));
+
+    let mut ga = ga(0.0, 0.0);
+    ga.with_population(genetic_algorithms::population::Population::new(individuals));
+    let population = ga.run();
+    assert_eq!(CALLS.with(|calls| calls.get()), 5);
+    assert_eq!(population.statistics[0].evaluations, 5);
+    assert!(ga.population.individuals.iter().all(|individual| individual.fitness_valid));
+}
+
+//Initializer that leaves the fitness of the individuals to the run
+struct Unevaluated;
+impl Initializer<CountedGenotype> for Unevaluated{
+    fn initialize(&self, context: &InitializationContext<CountedGenotype>) -> Vec<CountedGenotype> {
+        (0..context.population_size).map(|_| CountedGenotype{dna: context.random_dna(), ..Default::default()}).collect()
+    }
+}
+
+#[test]
+fn test_initializer_without_fitness(){
+    //The fitness of the initial population is calculated at the start of the run, once per individual
+    CALLS.with(|calls| calls.set(0));
+    let mut unevaluated = ga(1.0, 1.0);
+    unevaluated.with_initializer(Unevaluated);
+    let population = unevaluated.run();
+    assert_eq!(CALLS.with(|calls| calls.get()), 10 + 5 * 10);
+    assert_eq!(population.statistics.last().unwrap().evaluations, 10 + 5 * 10);
+    assert!(population.statistics.iter().all(|generation| generation.skipped_evaluations == 0));
+    for individual in &unevaluated.population.individuals {
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.fitness, expected.fitness);
+    }
+
+    //The built-in initializers calculate the fitness, so it's not calculated again
+    CALLS.with(|calls| calls.set(0));
+    let mut random = ga(1.0, 1.0);
+    random.with_initializer(Random);
+    let population = random.run();
+    assert_eq!(CALLS.with(|calls| calls.get()), 10 + 5 * 10);
+    assert_eq!(population.statistics.last().unwrap().evaluations, 10 + 5 * 10);
+    assert!(population.statistics.iter().all(|generation| generation.skipped_evaluations == 0));
+}
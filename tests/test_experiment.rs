@@ -0,0 +1,131 @@
+mod structures;
+use std::sync::Arc;
+use genetic_algorithms::{experiment::{Experiment, Parameter}, configuration::{GaConfiguration, ProblemSolving}, operations::{Selection, Crossover, Mutation, Survivor}, problems::{BinaryGene, Problem, knapsack::{Knapsack, KnapsackInstance}}, statistics::Summary, traits::ConfigurationT};
+use structures::{Gene, Genotype};
+
+fn configuration() -> GaConfiguration {
+    let mut configuration = GaConfiguration::new();
+    configuration.with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Swap)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(6)
+        .with_alleles_can_be_repeated(true)
+        .with_max_generations(15);
+    configuration.clone()
+}
+
+fn experiment() -> Experiment<Genotype> {
+    let mut experiment = Experiment::new(configuration());
+    experiment.with_alleles((1..9).map(|id| Gene{id}).collect()).with_runs(6).with_seed(7);
+    experiment
+}
+
+fn best_so_far(statistics: &[genetic_algorithms::statistics::GenerationStatistics]) -> Vec<f64> {
+    statistics.iter().map(|generation| generation.best_so_far).collect()
+}
+
+#[test]
+fn test_experiment_is_reproducible(){
+    let sequential = experiment().run();
+    let repeated = experiment().run();
+    let parallel = experiment().with_parallel_runs(4).run();
+
+    //The runs with the same seed are the same, whether they are executed in parallel or not
+    assert_eq!(sequential.len(), 1);
+    assert_eq!(sequential[0].name, "base");
+    assert_eq!(sequential[0].runs.len(), 6);
+    for results in [&repeated, &parallel] {
+        for (run, other) in sequential[0].runs.iter().zip(&results[0].runs) {
+            assert_eq!(run.seed, other.seed);
+            assert_eq!(run.best_fitness, other.best_fitness);
+            assert_eq!(best_so_far(&run.statistics), best_so_far(&other.statistics));
+        }
+    }
+    assert_eq!(sequential[0].runs.iter().map(|run| run.seed).collect::<Vec<u64>>(), vec![7, 8, 9, 10, 11, 12]);
+
+    //The runs with different seeds are independent
+    let runs = &sequential[0].runs;
+    assert!(runs.iter().any(|run| best_so_far(&run.statistics) != best_so_far(&runs[0].statistics)));
+}
+
+#[test]
+fn test_experiment_grid(){
+    let results = experiment().with_runs(2).with_grid(vec![
+        vec![Parameter::new("uniform", |configuration| {configuration.with_crossover_method(Crossover::Uniform);}),
+             Parameter::new("multipoint", |configuration| {configuration.with_crossover_method(Crossover::MultiPoint).with_crossover_number_of_points(2);})],
+        vec![Parameter::new("10 generations", |configuration| {configuration.with_max_generations(10);}),
+             Parameter::new("20 generations", |configuration| {configuration.with_max_generations(20);})],
+    ]).run();
+
+    //One configuration per combination of the parameters
+    let names: Vec<&str> = results.iter().map(|result| result.name.as_str()).collect();
+    assert_eq!(names, vec!["uniform, 10 generations", "uniform, 20 generations", "multipoint, 10 generations", "multipoint, 20 generations"]);
+    assert_eq!(results[2].configuration.crossover_configuration.method, Crossover::MultiPoint);
+    for result in &results {
+        let generations = result.configuration.limit_configuration.max_generations as usize;
+        assert_eq!(result.runs.len(), 2);
+        assert_eq!(result.summary.convergence.len(), generations);
+        assert!(result.runs.iter().all(|run| run.statistics.len() == generations));
+    }
+}
+
+#[test]
+fn test_experiment_summary(){
+    let results = experiment().run();
+    let result = &results[0];
+    let best_fitness: Vec<f64> = result.runs.iter().map(|run| run.best_fitness).collect();
+
+    //The summary aggregates the runs, and the convergence curve ends in the best fitness
+    assert_eq!(result.summary.best_fitness, Summary::new(&best_fitness));
+    assert_eq!(result.summary.convergence.last().unwrap().mean, result.summary.best_fitness.mean);
+    assert!(result.summary.convergence.windows(2).all(|generations| generations[0].mean <= generations[1].mean));
+
+    //Without target there is no success rate
+    assert!(result.summary.success_rate.is_none());
+    assert!(result.summary.expected_running_time.is_none());
+}
+
+#[test]
+fn test_experiment_success(){
+    //A target reached in the first generation
+    let results = experiment().with_success_target(1.0).run();
+    let summary = &results[0].summary;
+    let first_evaluations = results[0].runs[0].statistics[0].evaluations;
+    assert_eq!(summary.success_rate, Some(1.0));
+    assert!(results[0].runs.iter().all(|run| run.evaluations_to_success == Some(first_evaluations)));
+    assert_eq!(summary.expected_running_time, Some(first_evaluations as f64));
+
+    //A target that can't be reached
+    let results = experiment().with_success_target(1000.0).run();
+    assert_eq!(results[0].summary.success_rate, Some(0.0));
+    assert_eq!(results[0].summary.expected_running_time, Some(f64::INFINITY));
+}
+
+#[test]
+fn test_experiment_with_prototype(){
+    let instance = Arc::new(KnapsackInstance::new(vec![23, 31, 29, 44, 53, 38, 63, 85, 89, 82], vec![92, 57, 49, 68, 60, 43, 67, 84, 87, 72], 165));
+    let mut configuration = configuration();
+    configuration.with_problem_solving(Knapsack::problem_solving())
+        .with_mutation_method(Mutation::Creep)
+        .with_genes_per_individual(10);
+    let mut experiment = Experiment::<Knapsack>::new(configuration);
+    experiment.with_alleles(BinaryGene::alleles()).with_runs(3).with_seed(7);
+
+    //Every run is initialized with the instance of the prototype
+    let results = experiment.with_prototype(Knapsack::with_instance(instance.clone())).run();
+    assert_eq!(results[0].runs.len(), 3);
+    assert!(results[0].runs.iter().all(|run| run.best_fitness > 0.0 && run.best_fitness <= 309.0));
+}
+
+#[test]
+fn test_summary(){
+    let summary = Summary::new(&[4.0, 1.0, f64::NAN, 3.0, 2.0]);
+    assert_eq!((summary.min, summary.max, summary.mean, summary.median), (1.0, 4.0, 2.5, 2.5));
+    assert_eq!((summary.q1, summary.q3, summary.iqr), (1.75, 3.25, 1.5));
+    assert!((summary.std_dev - 1.25_f64.sqrt()).abs() < 1e-12);
+    assert!(Summary::new(&[]).median.is_nan());
+}
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod structures;
-use genetic_algorithms::{operations::{Selection, Crossover, Mutation, Survivor}, population::Population, traits::{GenotypeT, ConfigurationT}, configuration::ProblemSolving, ga};
+use genetic_algorithms::{operations::{Selection, Crossover, Mutation, Survivor, OperatorSelection, Replacement, Niching}, population::Population, traits::{GenotypeT, ConfigurationT}, configuration::{ProblemSolving, Schedule, Distance, AgaFormulation}, ga};
 use genetic_algorithms::ga::TerminationCause;
-use crate::structures::{Gene, Genotype};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
 extern crate num_cpus;
 
 #[test]
@@ -137,7 +137,7 @@ fn test_parent_crossover_repeating_alleles(){
 
     //Once population has been initialized, we check for each individual in the population the number of genes in the dna
     for individual in population.individuals{
-        assert!(individual.dna.len() == GENES_PER_INDIVIDUAL.try_into().unwrap());
+        assert!(individual.dna.len() == GENES_PER_INDIVIDUAL as usize);
     }
 }
 
@@ -215,4 +215,398 @@ fn test_callback_function(){
         .run_with_callback(Some(callback_function), 8);
 
     assert_eq!(population.individuals.len(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_adaptive_operator_selection(){
+
+    //Setup the alleles and initialize the population randomly
+    let alleles = vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4},
+                       Gene{id:5}, Gene{id:6}, Gene{id:7}, Gene{id:8}];
+
+    let callback = |_generation_number: &i32, population: &Population<Genotype>, _termination_cause: TerminationCause| {
+        //Every operator of the portfolio is reported with its probability
+        assert_eq!(population.crossover_usage.len(), 2);
+        assert_eq!(population.mutation_usage.len(), 3);
+        assert!((population.mutation_usage.iter().map(|usage| usage.probability).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert_eq!(population.crossover_usage[0].operator, Crossover::Uniform);
+    };
+
+    let population = ga::Ga::new()
+                    .with_threads(2)
+                    .with_problem_solving(ProblemSolving::Maximization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(10)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Swap)
+                    .with_survivor_method(Survivor::Fitness)
+                    .with_operator_selection_method(OperatorSelection::AdaptivePursuit)
+                    .with_crossover_operators(vec![Crossover::Uniform, Crossover::MultiPoint])
+                    .with_crossover_number_of_points(2)
+                    .with_mutation_operators(vec![Mutation::Swap, Mutation::Inversion, Mutation::Scramble])
+                    .with_population_size(20)
+                    .with_genes_per_individual(6)
+                    .with_alleles_can_be_repeated(true)
+                    .with_alleles(alleles)
+                    .with_max_generations(20)
+                    .run_with_callback(Some(callback), 1);
+
+    assert_eq!(population.individuals.len(), 1);
+}
+
+#[test]
+fn test_adaptive_operator_selection_with_lambda(){
+
+    //Only the operators of the couples of the children kept are credited: 3 children come from 2 couples
+    let alleles = vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4},
+                       Gene{id:5}, Gene{id:6}, Gene{id:7}, Gene{id:8}];
+
+    let callback = |_generation_number: &i32, population: &Population<Genotype>, _termination_cause: TerminationCause| {
+        assert_eq!(population.crossover_usage.iter().map(|usage| usage.applications).sum::<usize>(), 2);
+        assert_eq!(population.mutation_usage.iter().map(|usage| usage.applications).sum::<usize>(), 2);
+    };
+
+    ga::Ga::new()
+        .with_threads(1)
+        .with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Random)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Swap)
+        .with_survivor_method(Survivor::Fitness)
+        .with_lambda(3)
+        .with_operator_selection_method(OperatorSelection::UpperConfidenceBound)
+        .with_crossover_operators(vec![Crossover::Uniform, Crossover::MultiPoint])
+        .with_crossover_number_of_points(2)
+        .with_mutation_operators(vec![Mutation::Swap, Mutation::Inversion])
+        .with_population_size(10)
+        .with_genes_per_individual(6)
+        .with_alleles_can_be_repeated(true)
+        .with_alleles(alleles)
+        .with_max_generations(5)
+        .run_with_callback(Some(callback), 1);
+}
+
+#[test]
+fn test_parameter_control_schedules(){
+
+    let alleles = vec![Gene{id:1}, Gene{id:2}, Gene{id:3}, Gene{id:4},
+                       Gene{id:5}, Gene{id:6}, Gene{id:7}, Gene{id:8}];
+
+    let callback = |generation_number: &i32, population: &Population<Genotype>, termination_cause: TerminationCause| {
+        if termination_cause == TerminationCause::NotTerminated {
+            //Linear decay from 0.9 to 0.1 for the crossover and a halving every 5 generations for the mutation
+            assert!((population.crossover_probability - (0.9 - 0.8 * *generation_number as f64 / 9.0)).abs() < 1e-9);
+            assert!((population.mutation_probability - (0.4 * 0.5_f64.powi(*generation_number / 5))).abs() < 1e-9);
+        }
+    };
+
+    ga::Ga::new()
+        .with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_crossover_probability_max(0.9)
+        .with_crossover_probability_min(0.1)
+        .with_crossover_schedule(Schedule::Linear)
+        .with_mutation_method(Mutation::Swap)
+        .with_mutation_probability_max(0.4)
+        .with_mutation_schedule(Schedule::Step { step_size: 5, factor: 0.5 })
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(6)
+        .with_alleles_can_be_repeated(true)
+        .with_alleles(alleles)
+        .with_max_generations(10)
+        .run_with_callback(Some(callback), 1);
+}
+
+#[test]
+fn test_self_adaptive_mutation(){
+
+    //Individuals far from the optimum, with one step size per gene
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(10)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_survivor_method(Survivor::Fitness)
+                    .with_population(population)
+                    .with_max_generations(100)
+                    .run();
+
+    //The initial best fitness is 125 and the optimum is 0
+    assert!(best_population.individuals[0].get_fitness() < 125.0);
+}
+
+#[test]
+fn test_steady_state(){
+
+    //Individuals far from the optimum, with one step size per gene
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    //Each step only produces two children, which replace the worst individuals
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_steady_state(true)
+                    .with_offspring_per_step(2)
+                    .with_replacement_method(Replacement::Worst)
+                    .with_population(population)
+                    .with_max_generations(500)
+                    .run_with_callback(Some(|_generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                        //The population size is kept between steps
+                        assert_eq!(population.size(), 20);
+                    }), 1);
+
+    //The initial best fitness is 125 and the optimum is 0
+    assert!(best_population.individuals[0].get_fitness() < 125.0);
+}
+
+#[test]
+#[should_panic(expected = "For steady state, the number of offspring per step must be set.")]
+fn test_steady_state_without_offspring(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..4).map(|_| SelfAdaptiveGenotype{..Default::default()}).collect();
+    ga::Ga::new()
+        .with_problem_solving(ProblemSolving::Minimization)
+        .with_selection_method(Selection::Tournament)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Gaussian)
+        .with_steady_state(true)
+        .with_offspring_per_step(0)
+        .with_population(Population::new(individuals))
+        .run();
+}
+
+
+#[test]
+fn test_mu_plus_lambda(){
+
+    //Individuals far from the optimum, with one step size per gene
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..10).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    //The best fitness of the population can't get worse, as the parents compete with the children
+    let best_fitness = std::cell::Cell::new(f64::MAX);
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(5)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_survivor_method(Survivor::MuPlusLambda)
+                    .with_lambda(30)
+                    .with_population(population)
+                    .with_max_generations(50)
+                    .run_with_callback(Some(|_generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                        assert_eq!(population.size(), 10);
+                        let fitness = population.individuals.iter().map(|individual| individual.get_fitness()).fold(f64::MAX, f64::min);
+                        assert!(fitness <= best_fitness.get());
+                        best_fitness.set(fitness);
+                    }), 1);
+
+    //The initial best fitness is 125 and the optimum is 0
+    assert!(best_population.individuals[0].get_fitness() < 125.0);
+}
+
+#[test]
+fn test_mu_comma_lambda(){
+
+    //Individuals far from the optimum, with one step size per gene
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..10).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    //The parents are discarded in each generation, so every survivor is a child of the current generation
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(5)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_survivor_method(Survivor::MuCommaLambda)
+                    .with_lambda(40)
+                    .with_population(population)
+                    .with_max_generations(50)
+                    .run_with_callback(Some(|generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                        assert_eq!(population.size(), 10);
+                        let age = population.individuals[0].get_age();
+                        assert!(age >= *generation);
+                        assert!(population.individuals.iter().all(|individual| individual.get_age() == age));
+                    }), 1);
+
+    //The initial best fitness is 125 and the optimum is 0
+    assert!(best_population.individuals[0].get_fitness() < 125.0);
+}
+
+#[test]
+#[should_panic(expected = "For the (mu, lambda) survivor, the lambda must be greater or equal than the population size.")]
+fn test_mu_comma_lambda_smaller_than_mu(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..10).map(|_| SelfAdaptiveGenotype{..Default::default()}).collect();
+    ga::Ga::new()
+        .with_problem_solving(ProblemSolving::Minimization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Gaussian)
+        .with_survivor_method(Survivor::MuCommaLambda)
+        .with_lambda(6)
+        .with_population(Population::new(individuals))
+        .run();
+}
+
+#[test]
+fn test_niching_survivors(){
+    for survivor in [Survivor::DeterministicCrowding, Survivor::RestrictedTournament] {
+        let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+            let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+            SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+        }).collect();
+        let population = Population::new(individuals);
+
+        //Each child replaces a similar individual, so the population size is kept
+        let best_population = ga::Ga::new()
+                        .with_problem_solving(ProblemSolving::Minimization)
+                        .with_selection_method(Selection::Tournament)
+                        .with_number_of_couples(10)
+                        .with_crossover_method(Crossover::Uniform)
+                        .with_mutation_method(Mutation::Gaussian)
+                        .with_survivor_method(survivor)
+                        .with_window_size(5)
+                        .with_population(population)
+                        .with_max_generations(100)
+                        .run_with_callback(Some(|_generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                            assert_eq!(population.size(), 20);
+                        }), 1);
+
+        //The initial best fitness is 125 and the optimum is 0
+        assert!(best_population.individuals[0].get_fitness() < 125.0);
+    }
+}
+
+#[test]
+fn test_fitness_sharing_and_clearing(){
+    for niching in [Niching::FitnessSharing, Niching::Clearing] {
+        let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+            let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+            SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+        }).collect();
+        let population = Population::new(individuals);
+
+        //The parents are selected by their niche fitness, but the population keeps the real fitness
+        let best_population = ga::Ga::new()
+                        .with_problem_solving(ProblemSolving::Minimization)
+                        .with_selection_method(Selection::Tournament)
+                        .with_number_of_couples(10)
+                        .with_crossover_method(Crossover::Uniform)
+                        .with_mutation_method(Mutation::Gaussian)
+                        .with_survivor_method(Survivor::Fitness)
+                        .with_niching_method(niching)
+                        .with_sigma_share(0.5)
+                        .with_niche_capacity(2)
+                        .with_population(population)
+                        .with_max_generations(100)
+                        .run_with_callback(Some(|_generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                            for individual in &population.individuals {
+                                let fitness: f64 = individual.dna.iter().map(|gene| gene.value * gene.value).sum();
+                                assert_eq!(individual.get_fitness(), fitness);
+                            }
+                        }), 1);
+
+        //The initial best fitness is 125 and the optimum is 0
+        assert!(best_population.individuals[0].get_fitness() < 125.0);
+    }
+}
+
+#[test]
+fn test_generation_statistics(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(10)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_survivor_method(Survivor::Fitness)
+                    .with_diversity_statistics(true)
+                    .with_distance_metric(Distance::Euclidean)
+                    .with_population(population)
+                    .with_max_generations(30)
+                    .run();
+
+    //The statistics and the diversity are recorded for each generation
+    assert_eq!(best_population.statistics.len(), 30);
+    for (generation, statistics) in best_population.statistics.iter().enumerate() {
+        assert_eq!(statistics.generation, generation as i32);
+        assert!(statistics.min <= statistics.median && statistics.median <= statistics.max);
+        assert!(statistics.best_so_far <= statistics.min);
+        let diversity = statistics.diversity.unwrap();
+        assert!(diversity.mean_distance >= 0.0);
+        assert!(diversity.unique_genotypes >= 1 && diversity.unique_genotypes <= 20);
+    }
+
+    //The best fitness so far never gets worse and the evaluations keep growing
+    for statistics in best_population.statistics.windows(2) {
+        assert!(statistics[1].best_so_far <= statistics[0].best_so_far);
+        assert!(statistics[1].evaluations > statistics[0].evaluations);
+    }
+}
+
+
+#[test]
+fn test_srinivas_patnaik_aga(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..20).map(|i| {
+        let dna = (0..5).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 5], fitness: 0.0, age: 0}
+    }).collect();
+    let population = Population::new(individuals);
+
+    //The statistics of the adaptive ga are direction-aware and recalculated after the survivor selection
+    let best_population = ga::Ga::new()
+                    .with_problem_solving(ProblemSolving::Minimization)
+                    .with_adaptive_ga(true)
+                    .with_aga_formulation(AgaFormulation::SrinivasPatnaik)
+                    .with_selection_method(Selection::Tournament)
+                    .with_number_of_couples(10)
+                    .with_crossover_method(Crossover::Uniform)
+                    .with_crossover_probability_max(1.0)
+                    .with_crossover_probability_min(0.1)
+                    .with_mutation_method(Mutation::Gaussian)
+                    .with_mutation_probability_max(0.5)
+                    .with_mutation_probability_min(0.05)
+                    .with_survivor_method(Survivor::Fitness)
+                    .with_population(population)
+                    .with_max_generations(100)
+                    .run_with_callback(Some(|_generation: &i32, population: &Population<SelfAdaptiveGenotype>, _termination_cause: TerminationCause| {
+                        let best_fitness = population.individuals.iter().map(|individual| individual.get_fitness()).fold(f64::MAX, f64::min);
+                        let average_fitness = population.individuals.iter().map(|individual| individual.get_fitness()).sum::<f64>() / population.size() as f64;
+                        assert_eq!(population.f_max, -best_fitness);
+                        assert!((population.f_avg + average_fitness).abs() < 1e-9);
+                    }), 1);
+
+    //The initial best fitness is 125 and the optimum is 0
+    assert!(best_population.individuals[0].get_fitness() < 125.0);
+}
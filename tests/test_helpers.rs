@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod structures;
-use genetic_algorithms::helpers;
+use std::cmp::Ordering;
+use genetic_algorithms::{helpers, configuration::{GaConfiguration, LimitConfiguration, ProblemSolving}, traits::ConfigurationT};
 use crate::structures::{Gene, Genotype};
 
 #[test]
@@ -32,4 +33,48 @@ fn test_initialize_dna_without_repeated_alleles(){
         alleles_ids.push(gene.id);
     }
 
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_fitness_ordering(){
+    let individual_1 = Genotype{dna: vec![Gene{id:1}], fitness: 1.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}], fitness: 3.0, age: 0};
+    let individual_nan = Genotype{dna: vec![Gene{id:1}], fitness: f64::NAN, age: 0};
+
+    //The better individual is the lower one, and NaN is the worst in every problem
+    let minimization = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+    assert_eq!(helpers::fitness_ordering(&individual_1, &individual_2, &minimization), Ordering::Less);
+    assert_eq!(helpers::fitness_ordering(&individual_nan, &individual_2, &minimization), Ordering::Greater);
+
+    let maximization = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+    assert_eq!(helpers::fitness_ordering(&individual_1, &individual_2, &maximization), Ordering::Greater);
+    assert_eq!(helpers::fitness_ordering(&individual_nan, &individual_1, &maximization), Ordering::Greater);
+
+    let fixed_fitness = LimitConfiguration{problem_solving: ProblemSolving::FixedFitness, fitness_target: Some(2.5), ..Default::default()};
+    assert_eq!(helpers::fitness_ordering(&individual_1, &individual_2, &fixed_fitness), Ordering::Greater);
+    assert_eq!(helpers::fitness_ordering(&individual_nan, &individual_1, &fixed_fitness), Ordering::Greater);
+}
+
+fn allele_check_configuration(alleles_can_be_repeated: bool) -> GaConfiguration {
+    let mut configuration = GaConfiguration::new();
+    configuration.with_population_size(4).with_genes_per_individual(6).with_number_of_couples(2).with_alleles_can_be_repeated(alleles_can_be_repeated);
+    configuration
+}
+
+#[test]
+fn test_alleles_check_with_repeated_alleles(){
+    let alleles = vec![Gene{id:0}, Gene{id:1}];
+
+    //With repeated alleles, the individuals can have more genes than alleles
+    helpers::condition_checker_factory::<Genotype>(Some(&allele_check_configuration(true)), None, Some(&alleles), &[], true);
+
+    //Without repeated alleles, an initial population given by the user doesn't need the alleles
+    helpers::condition_checker_factory::<Genotype>(Some(&allele_check_configuration(false)), None, Some(&alleles), &[], false);
+}
+
+#[test]
+#[should_panic(expected = "The number of genes within an individual should not be more than the different alleles.")]
+fn test_alleles_check_without_repeated_alleles(){
+    let alleles = vec![Gene{id:0}, Gene{id:1}];
+    helpers::condition_checker_factory::<Genotype>(Some(&allele_check_configuration(false)), None, Some(&alleles), &[], true);
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod structures;
+use std::fs;
+use genetic_algorithms::{history, configuration::{HistoryConfiguration, HistoryFormat, ProblemSolving}, operations::{Selection, Crossover, Mutation, Survivor}, population::Population, traits::ConfigurationT, ga};
+use crate::structures::{Gene, Genotype, RealGene, SelfAdaptiveGenotype};
+
+fn temporary_path(name: &str) -> String {
+    std::env::temp_dir().join(format!("genetic_algorithms_{}_{}", std::process::id(), name)).to_string_lossy().to_string()
+}
+
+#[test]
+fn test_best_individual_to_string(){
+    let individual = Genotype{dna: vec![Gene{id:3}, Gene{id:1}], fitness: 2.5, age: 0};
+
+    assert_eq!(history::best_individual_to_string(&individual, HistoryFormat::Csv), "locus,id,value\n0,3,3\n1,1,1\n");
+    assert_eq!(history::best_individual_to_string(&individual, HistoryFormat::JsonLines), "{\"fitness\":2.5,\"dna\":[{\"id\":3,\"value\":3},{\"id\":1,\"value\":1}]}\n");
+}
+
+#[test]
+fn test_export_history(){
+    let individual = Genotype{dna: vec![Gene{id:3}, Gene{id:1}], fitness: 2.5, age: 0};
+    let configuration = HistoryConfiguration{export_history: true, history_path: temporary_path("history.csv"), history_format: HistoryFormat::Csv,
+                                             best_individual_path: Some(temporary_path("best.csv"))};
+
+    history::export(&[], &individual, &configuration).unwrap();
+    assert!(fs::read_to_string(&configuration.history_path).unwrap().starts_with("generation,"));
+    assert_eq!(fs::read_to_string(configuration.best_individual_path.as_ref().unwrap()).unwrap(), "locus,id,value\n0,3,3\n1,1,1\n");
+
+    fs::remove_file(&configuration.history_path).unwrap();
+    fs::remove_file(configuration.best_individual_path.unwrap()).unwrap();
+}
+
+#[test]
+fn test_ga_export_history(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..10).map(|i| {
+        let dna = (0..3).map(|j| RealGene{id: j, value: 5.0 + (i + j) as f64 * 0.1}).collect();
+        SelfAdaptiveGenotype{dna, strategy_parameters: vec![1.0; 3], fitness: 0.0, age: 0}
+    }).collect();
+    let (history_path, best_individual_path) = (temporary_path("ga_history.jsonl"), temporary_path("ga_best.jsonl"));
+
+    ga::Ga::new()
+        .with_problem_solving(ProblemSolving::Minimization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Gaussian)
+        .with_survivor_method(Survivor::Fitness)
+        .with_export_history(true)
+        .with_history_path(history_path.clone())
+        .with_history_format(HistoryFormat::JsonLines)
+        .with_best_individual_path(best_individual_path.clone())
+        .with_population(Population::new(individuals))
+        .with_max_generations(20)
+        .run();
+
+    //One record per generation and the best individual with its genes
+    let history = fs::read_to_string(&history_path).unwrap();
+    assert_eq!(history.lines().count(), 20);
+    assert!(history.lines().all(|line| line.starts_with("{\"generation\":") && line.ends_with('}')));
+    let best_individual = fs::read_to_string(&best_individual_path).unwrap();
+    assert!(best_individual.starts_with("{\"fitness\":"));
+    assert_eq!(best_individual.matches("\"id\"").count(), 3);
+
+    fs::remove_file(history_path).unwrap();
+    fs::remove_file(best_individual_path).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "For the history export, the history path must be set.")]
+fn test_ga_export_history_without_path(){
+    let individuals: Vec<SelfAdaptiveGenotype> = (0..4).map(|_| SelfAdaptiveGenotype{..Default::default()}).collect();
+    ga::Ga::new()
+        .with_problem_solving(ProblemSolving::Minimization)
+        .with_selection_method(Selection::Tournament)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Gaussian)
+        .with_export_history(true)
+        .with_population(Population::new(individuals))
+        .run();
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod structures;
+use genetic_algorithms::{configuration::{LimitConfiguration, ProblemSolving}, domain::Domain, ga::Ga, operations::{Crossover, Mutation, Selection, Survivor}, traits::{ConfigurationT, GenotypeT},
+    initialization::{Initializer, InitializationContext, random::Random, latin_hypercube::LatinHypercube, opposition::OppositionBased, heuristic::Heuristic, seeded::Seeded}};
+use crate::structures::{Gene, Genotype, SelfAdaptiveGenotype};
+
+fn alleles() -> Vec<Gene> {
+    (1..9).map(|id| Gene{id}).collect()
+}
+
+fn context<'a, U: GenotypeT>(alleles: &'a [U::Gene], domains: &'a [Domain<U::Gene>], limit_configuration: &'a LimitConfiguration, population_size: usize, genes_per_individual: usize) -> InitializationContext<'a, U> {
+    InitializationContext { population_size, genes_per_individual, alleles, domains, alleles_can_be_repeated: limit_configuration.alleles_can_be_repeated,
+                            needs_unique_ids: limit_configuration.needs_unique_ids, limit_configuration, prototype: None }
+}
+
+fn is_permutation(individual: &Genotype) -> bool {
+    let mut ids: Vec<i32> = individual.dna.iter().map(|gene| gene.id).collect();
+    ids.sort();
+    ids.dedup();
+    ids.len() == individual.dna.len()
+}
+
+#[test]
+fn test_random_initializer(){
+    let alleles = alleles();
+    let limit_configuration = LimitConfiguration{..Default::default()};
+    let individuals = Random.initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 12, 5));
+
+    assert_eq!(individuals.len(), 12);
+    for individual in &individuals {
+        assert_eq!(individual.dna.len(), 5);
+        assert!(is_permutation(individual));
+        let mut expected = individual.clone();
+        expected.calculate_fitness();
+        assert_eq!(individual.fitness, expected.fitness);
+    }
+}
+
+#[test]
+fn test_latin_hypercube_strata(){
+    let limit_configuration = LimitConfiguration{needs_unique_ids: true, ..Default::default()};
+    let domains = vec![Domain::Real(0.0, 10.0), Domain::Real(-1.0, 1.0)];
+    let individuals = LatinHypercube.initialize(&context::<SelfAdaptiveGenotype>(&[], &domains, &limit_configuration, 10, 2));
+
+    //Every stratum of every locus has one individual
+    for (locus, (min, width)) in [(0.0, 1.0), (-1.0, 0.2)].into_iter().enumerate() {
+        let mut strata: Vec<usize> = individuals.iter().map(|individual| ((individual.dna[locus].value - min) / width) as usize).collect();
+        strata.sort();
+        assert_eq!(strata, (0..10).collect::<Vec<usize>>());
+    }
+    assert!(individuals.iter().all(|individual| individual.dna.iter().enumerate().all(|(locus, gene)| gene.id == locus as i32)));
+
+    //Without domains, every allele is taken once in each locus
+    let alleles = alleles();
+    let limit_configuration = LimitConfiguration{alleles_can_be_repeated: true, ..Default::default()};
+    let individuals = LatinHypercube.initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 8, 3));
+    for locus in 0..3 {
+        let mut values: Vec<f64> = individuals.iter().map(|individual| individual.dna[locus].id as f64).collect();
+        values.sort_by(f64::total_cmp);
+        assert_eq!(values, (1..9).map(|id| id as f64).collect::<Vec<f64>>());
+    }
+}
+
+#[test]
+fn test_opposition_based(){
+    //The best of each value and its opposite is kept, so every value is at most 0.5 when minimizing the squares
+    let limit_configuration = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+    let domains = vec![Domain::Real(0.0, 1.0)];
+    let individuals = OppositionBased.initialize(&context::<SelfAdaptiveGenotype>(&[], &domains, &limit_configuration, 20, 1));
+    assert_eq!(individuals.len(), 20);
+    assert!(individuals.iter().all(|individual| individual.dna[0].value <= 0.5));
+    assert!(individuals.windows(2).all(|pair| pair[0].fitness <= pair[1].fitness));
+
+    //Without repetition, the opposites keep the alleles unique
+    let alleles = alleles();
+    let limit_configuration = LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()};
+    let individuals = OppositionBased.initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 10, 6));
+    assert_eq!(individuals.len(), 10);
+    assert!(individuals.iter().all(is_permutation));
+}
+
+#[test]
+fn test_heuristic_seeding(){
+    let alleles = alleles();
+    let limit_configuration = LimitConfiguration{..Default::default()};
+    let heuristic = Heuristic::<Genotype>::new(3, |index| (0..4).map(|locus| Gene{id: (index + locus) as i32 + 1}).collect());
+    let individuals = heuristic.initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 6, 4));
+
+    assert_eq!(individuals.len(), 6);
+    for (index, individual) in individuals.iter().take(3).enumerate() {
+        assert_eq!(individual.dna.iter().map(|gene| gene.id).collect::<Vec<i32>>(), (1..5).map(|id| id + index as i32).collect::<Vec<i32>>());
+    }
+    assert!(individuals.iter().all(is_permutation));
+}
+
+#[test]
+fn test_seeded_initialization(){
+    let alleles = alleles();
+    let limit_configuration = LimitConfiguration{..Default::default()};
+    let seeds = vec![Genotype{dna: vec![Gene{id: 8}, Gene{id: 7}, Gene{id: 6}], fitness: 0.0, age: 4}];
+    let individuals = Seeded::new(seeds.clone()).initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 5, 3));
+
+    //The seeds are evaluated and start with age 0
+    assert_eq!(individuals.len(), 5);
+    assert_eq!(individuals[0].dna, seeds[0].dna);
+    assert_eq!(individuals[0].age, 0);
+    assert_eq!(individuals[0].fitness, 8.0 * 0.0 + 7.0 * 1.0 + 6.0 * 2.0);
+    assert!(individuals.iter().all(|individual| individual.dna.len() == 3));
+
+    //Only the first seeds are taken
+    let individuals = Seeded::new(vec![seeds[0].clone(); 4]).initialize(&context::<Genotype>(&alleles, &[], &limit_configuration, 2, 3));
+    assert_eq!(individuals.len(), 2);
+}
+
+#[test]
+fn test_ga_with_initializer(){
+    let seed = Genotype{dna: alleles(), fitness: 0.0, age: 0};
+    let mut ga = Ga::<Genotype>::new();
+    ga.with_problem_solving(ProblemSolving::Maximization)
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(5)
+        .with_crossover_method(Crossover::Cycle)
+        .with_mutation_method(Mutation::Swap)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(10)
+        .with_genes_per_individual(8)
+        .with_max_generations(2);
+    ga.with_alleles(alleles()).with_initializer(Seeded::new(vec![seed.clone()]));
+
+    let population = ga.random_initialization();
+    assert_eq!(population.individuals.len(), 10);
+    assert_eq!(population.individuals[0].dna, seed.dna);
+
+    //The seed is the optimum, with the alleles sorted, and the survivors keep it
+    let population = ga.run();
+    let best = population.individuals.iter().map(|individual| individual.fitness).fold(f64::MIN, f64::max);
+    assert_eq!(best, (1..9).map(|id| (id * (id - 1)) as f64).sum::<f64>());
+}
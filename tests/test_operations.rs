@@ -3,5 +3,8 @@ mod operations{
     mod test_mutation;
     mod test_selection;
     mod test_survivor;
+    mod test_operator_selection;
+    mod test_replacement;
+    mod test_niching;
 }
 mod structures;
\ No newline at end of file
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod structures;
+use std::{collections::HashSet, panic::{self, AssertUnwindSafe}, thread, time::Duration};
+use genetic_algorithms::{configuration::ProblemSolving, ga::Ga, operations::{Crossover, Mutation, Selection, Survivor}, parallel::{chunk_ranges, chunk_sizes, chunks_mut, WorkerPool}, rng, traits::{ConfigurationT, GenotypeT}};
+use crate::structures::{Gene, Genotype};
+
+#[test]
+fn test_chunk_sizes(){
+    for elements in 0..200 {
+        for threads in 0..17 {
+            let sizes = chunk_sizes(elements, threads);
+
+            //Every element is in a chunk, without empty chunks nor more chunks than threads
+            assert_eq!(sizes.iter().sum::<usize>(), elements);
+            assert_eq!(sizes.len(), if elements == 0 {0} else {threads.clamp(1, elements)});
+            assert!(sizes.iter().all(|size| *size > 0));
+
+            //The remainder is spread over the first chunks
+            assert!(sizes.windows(2).all(|pair| pair[0] >= pair[1] && pair[0] - pair[1] <= 1));
+        }
+    }
+    assert_eq!(chunk_sizes(100, 3), vec![34, 33, 33]);
+    assert_eq!(chunk_sizes(3, 8), vec![1, 1, 1]);
+}
+
+#[test]
+fn test_chunk_ranges(){
+    for elements in 0..200 {
+        for threads in 0..17 {
+            let ranges = chunk_ranges(elements, threads);
+            let sizes: Vec<usize> = ranges.iter().map(|range| range.len()).collect();
+            assert_eq!(sizes, chunk_sizes(elements, threads));
+
+            //The ranges are consecutive and cover every index
+            let indexes: Vec<usize> = ranges.into_iter().flatten().collect();
+            assert_eq!(indexes, (0..elements).collect::<Vec<usize>>());
+        }
+    }
+}
+
+#[test]
+fn test_chunks_mut(){
+    let mut elements: Vec<usize> = (0..10).collect();
+    let chunks = chunks_mut(&mut elements, 4);
+    assert_eq!(chunks.iter().map(|chunk| chunk.len()).collect::<Vec<usize>>(), vec![3, 3, 2, 2]);
+    for chunk in chunks {
+        chunk.iter_mut().for_each(|element| *element *= 2);
+    }
+    assert_eq!(elements, (0..10).map(|element| element * 2).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_worker_pool(){
+    //The results keep the order of the inputs, borrowing the data of the caller
+    let data: Vec<usize> = (0..1000).collect();
+    for threads in 1..9 {
+        let pool = WorkerPool::new(threads);
+        let sums = pool.map(chunk_ranges(data.len(), threads), |range| data[range].iter().sum::<usize>());
+        assert_eq!(sums.len(), threads);
+        assert_eq!(sums.iter().sum::<usize>(), data.iter().sum::<usize>());
+        assert_eq!(sums[0], data[chunk_ranges(data.len(), threads)[0].clone()].iter().sum::<usize>());
+    }
+
+    //The tasks are seeded from the current thread, so the seeded initialization in several threads is reproducible
+    let initialization = || {
+        let mut ga = Ga::<Genotype>::new();
+        ga.with_population_size(20).with_genes_per_individual(5).with_threads(4).with_problem_solving(ProblemSolving::Maximization);
+        ga.with_alleles((1..101).map(|id| Gene{id}).collect());
+        ga.random_initialization().individuals.into_iter().map(|individual| individual.dna).collect::<Vec<Vec<Gene>>>()
+    };
+    rng::set_seed(Some(3));
+    let first = initialization();
+    rng::set_seed(Some(3));
+    let second = initialization();
+    rng::set_seed(None);
+    assert!(first == second);
+}
+
+#[test]
+fn test_worker_pool_reuse(){
+    //The tasks of every call run in at most as many workers as threads, and the rayon workers are kept alive between the calls
+    let pool = WorkerPool::new(4);
+    let mut workers = HashSet::new();
+    for _ in 0..50 {
+        let threads: HashSet<thread::ThreadId> = pool.map((0..8).collect(), |_: usize| {
+            thread::sleep(Duration::from_micros(100));
+            thread::current().id()
+        }).into_iter().collect();
+        assert!(threads.len() <= 4);
+        workers.extend(threads);
+    }
+    if cfg!(feature = "rayon") {
+        assert!(workers.len() <= 4);
+    }
+    assert!(!workers.contains(&thread::current().id()));
+
+    //The panic of a task is raised in the caller, and the pool can still be used
+    let result = panic::catch_unwind(AssertUnwindSafe(|| pool.map((0..8).collect(), |input: usize| if input == 5 {panic!("task failed")} else {input})));
+    assert!(result.is_err());
+    assert_eq!(pool.map((0..8).collect(), |input: usize| input * 2), (0..8).map(|input| input * 2).collect::<Vec<usize>>());
+
+    //The tasks can use the same pool
+    let sums = pool.map((0..8).collect(), |input: usize| pool.map((0..8).collect(), |other: usize| input * other).into_iter().sum::<usize>());
+    assert_eq!(sums, (0..8).map(|input| input * 28).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_concurrent_evaluation(){
+    for threads in 1..9 {
+        let mut individuals: Vec<Genotype> = (0..37).map(|id| Genotype{dna: vec![Gene{id}, Gene{id: id + 1}], fitness: -1.0, age: 0}).collect();
+        WorkerPool::new(threads).evaluate(&mut individuals);
+        assert!(individuals.iter().all(|individual| individual.fitness == individual.dna[1].id as f64));
+    }
+}
+
+#[test]
+fn test_map_owned_chunks(){
+    //The individuals are moved to the workers in chunks and back to their positions
+    for threads in 1..9 {
+        let mut individuals: Vec<Genotype> = (0..37).map(|id| Genotype{dna: vec![Gene{id}], fitness: 0.0, age: 0}).collect();
+        let sizes = WorkerPool::new(threads).map_owned_chunks(&mut individuals, |chunk| {
+            chunk.iter_mut().for_each(|individual| individual.age += 1);
+            chunk.len()
+        });
+        assert_eq!(sizes, chunk_sizes(37, threads));
+        assert!(individuals.iter().enumerate().all(|(id, individual)| individual.dna[0].id == id as i32 && individual.age == 1));
+    }
+}
+
+#[test]
+fn test_map_owned_chunks_with_panic(){
+    //The individuals are moved back to their positions before the panic of a chunk is raised
+    for threads in 1..9 {
+        let mut individuals: Vec<Genotype> = (0..37).map(|id| Genotype{dna: vec![Gene{id}], fitness: 0.0, age: 0}).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| WorkerPool::new(threads).map_owned_chunks(&mut individuals, |chunk| {
+            chunk.iter_mut().for_each(|individual| individual.age += 1);
+            if chunk.iter().any(|individual| individual.dna[0].id == 20) {
+                panic!("chunk failed");
+            }
+        })));
+        assert!(result.is_err());
+        assert!(individuals.iter().enumerate().all(|(id, individual)| individual.dna[0].id == id as i32 && individual.age == 1));
+    }
+}
+
+#[test]
+fn test_multithreaded_initialization_and_run(){
+    let alleles: Vec<Gene> = (1..11).map(|id| Gene{id}).collect();
+    for threads in 1..9 {
+        for population_size in [7, 10, 25, 100] {
+            let mut ga = Ga::<Genotype>::new();
+            ga.with_problem_solving(ProblemSolving::Maximization)
+                .with_selection_method(Selection::Random)
+                .with_number_of_couples(population_size / 2)
+                .with_crossover_method(Crossover::Cycle)
+                .with_mutation_method(Mutation::Swap)
+                .with_survivor_method(Survivor::Fitness)
+                .with_population_size(population_size)
+                .with_genes_per_individual(10)
+                .with_threads(threads)
+                .with_max_generations(2);
+            ga.with_alleles(alleles.clone());
+
+            //Exactly the population size, whatever the number of threads
+            let population = ga.random_initialization();
+            assert_eq!(population.individuals.len(), population_size as usize);
+
+            //The fitness of every individual is calculated in the threads
+            ga.run();
+            assert_eq!(ga.population.individuals.len(), population_size as usize);
+            for individual in &ga.population.individuals {
+                let mut expected = individual.clone();
+                expected.calculate_fitness();
+                assert_eq!(individual.get_fitness(), expected.get_fitness());
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod structures;
 use crate::structures::{Genotype, Gene};
-use genetic_algorithms::population::Population;
+use genetic_algorithms::{population::Population, configuration::{Distance, LimitConfiguration, ProblemSolving}};
 
 #[test]
 fn test_add_individual_gn_aga(){
@@ -76,4 +76,101 @@ fn test_add_individuals(){
 
     //We check the computations
     assert_eq!(population.size(), 3);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_add_scored_individuals_minimization(){
+
+    //Setup of the project
+    let individual_1 = Genotype{dna: Vec::<Gene>::new(), fitness: 20.0, age: 0};
+    let individual_2 = Genotype{dna: Vec::<Gene>::new(), fitness: 40.0, age: 0};
+    let individual_3 = Genotype{dna: Vec::<Gene>::new(), fitness: 120.0, age: 0};
+    let mut individuals = vec![individual_1.clone(), individual_2.clone(), individual_3.clone()];
+    let limit_configuration = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+
+    //We check the computations, over the scores of a minimization problem
+    let mut population  = Population::new_empty();
+    population.add_scored_individuals(&mut individuals, Some(&limit_configuration));
+    assert_eq!(population.f_max, -20.0);
+    assert_eq!(population.f_avg, -60.0);
+    assert_eq!(population.size(), 3);
+
+    //The individuals added 1 by 1 get the same statistics
+    let mut population  = Population::new_empty();
+    for individual in [individual_1, individual_2, individual_3] {
+        population.add_scored_individual_gn(individual, 0, Some(&limit_configuration));
+    }
+    assert_eq!(population.f_max, -20.0);
+    assert_eq!(population.f_avg, -60.0);
+}
+
+#[test]
+fn test_add_scored_individuals_incrementally(){
+    //The statistics updated with each individual are the statistics of the whole population, without the NaN fitness
+    let limit_configuration = LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()};
+    let mut population = Population::new(vec![Genotype{dna: Vec::<Gene>::new(), fitness: 10.0, age: 0}, Genotype{dna: Vec::<Gene>::new(), fitness: -5.0, age: 0}]);
+    population.aga_statistics(&limit_configuration);
+    let mut individuals = vec![Genotype{dna: Vec::<Gene>::new(), fitness: f64::NAN, age: 0}, Genotype{dna: Vec::<Gene>::new(), fitness: -30.0, age: 0}];
+    population.add_scored_individuals(&mut individuals, Some(&limit_configuration));
+
+    let (f_max, f_avg) = (population.f_max, population.f_avg);
+    population.aga_statistics(&limit_configuration);
+    assert_eq!((f_max, f_avg), (population.f_max, population.f_avg));
+    assert_eq!(population.scored_individuals, 3);
+}
+
+#[test]
+fn test_population_diversity(){
+
+    //Two equal individuals and a different one
+    let individual_1 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}], fitness: 0.0, age: 0};
+    let individual_2 = Genotype{dna: vec![Gene{id:1}, Gene{id:2}], fitness: 0.0, age: 0};
+    let individual_3 = Genotype{dna: vec![Gene{id:1}, Gene{id:3}], fitness: 0.0, age: 0};
+    let population = Population::new(vec![individual_1, individual_2, individual_3]);
+
+    //The pairs have 0, 1 and 1 different genes
+    assert_eq!(population.mean_pairwise_distance(Distance::Hamming), 2.0 / 3.0);
+    assert_eq!(population.unique_genotypes(), 2);
+
+    //The first locus has a single gene, and the second one has two genes with probabilities 2/3 and 1/3
+    let locus_entropy = population.locus_entropy();
+    let expected_entropy = -(2.0 / 3.0_f64) * (2.0 / 3.0_f64).log2() - (1.0 / 3.0_f64) * (1.0 / 3.0_f64).log2();
+    assert_eq!(locus_entropy[0], 0.0);
+    assert!((locus_entropy[1] - expected_entropy).abs() < 1e-12);
+
+    let diversity = population.diversity(Distance::Genotype);
+    assert_eq!(diversity.unique_genotypes, 2);
+    assert!((diversity.entropy - expected_entropy / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_population_diversity_empty(){
+    let population: Population<Genotype> = Population::new_empty();
+    let diversity = population.diversity(Distance::Hamming);
+    assert_eq!(diversity.mean_distance, 0.0);
+    assert_eq!(diversity.entropy, 0.0);
+    assert_eq!(diversity.unique_genotypes, 0);
+}
+
+
+#[test]
+fn test_aga_statistics(){
+    let individuals = vec![Genotype{dna: Vec::<Gene>::new(), fitness: -20.0, age: 0}, Genotype{dna: Vec::<Gene>::new(), fitness: -40.0, age: 0},
+    Genotype{dna: Vec::<Gene>::new(), fitness: f64::NAN, age: 0}, Genotype{dna: Vec::<Gene>::new(), fitness: -120.0, age: 0}];
+    let mut population = Population::new(individuals);
+
+    //With negative fitness, f_max is not stuck at 0.0
+    population.aga_statistics(&LimitConfiguration{problem_solving: ProblemSolving::Maximization, ..Default::default()});
+    assert_eq!(population.f_max, -20.0);
+    assert_eq!(population.f_avg, -60.0);
+
+    //In minimization problems, f_max is the score of the individual with the lowest fitness
+    population.aga_statistics(&LimitConfiguration{problem_solving: ProblemSolving::Minimization, ..Default::default()});
+    assert_eq!(population.f_max, 120.0);
+    assert_eq!(population.f_avg, 60.0);
+
+    //In fixed fitness problems, the scores are the opposite of the distances to the target
+    population.aga_statistics(&LimitConfiguration{problem_solving: ProblemSolving::FixedFitness, fitness_target: Some(-40.0), ..Default::default()});
+    assert_eq!(population.f_max, 0.0);
+    assert_eq!(population.f_avg, -(20.0 + 0.0 + 80.0) / 3.0);
+}
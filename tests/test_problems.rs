@@ -0,0 +1,187 @@
+use std::sync::Arc;
+use genetic_algorithms::{configuration::ProblemSolving, ga::Ga, operations::{Crossover, Mutation, Selection, Survivor}, rng, traits::{ConfigurationT, GeneT, GenotypeT},
+    problems::{BinaryGene, IntegerGene, Problem, binary::{LeadingOnes, OneMax, Trap}, continuous::{Ackley, Continuous, Rastrigin, Rosenbrock, Schwefel, Sphere}, knapsack::{Knapsack, KnapsackInstance}, nk::{NkInstance, NkLandscape}, queens::NQueens}};
+
+fn binary(bits: &str) -> Vec<BinaryGene> {
+    bits.chars().map(|bit| BinaryGene{id: if bit == '1' {1} else {0}}).collect()
+}
+
+fn evaluate<U: GenotypeT>(mut individual: U, dna: &[U::Gene]) -> U {
+    individual.set_dna(dna);
+    individual.calculate_fitness();
+    individual
+}
+
+#[test]
+fn test_binary_gene_values(){
+    let mut gene = BinaryGene{id: 0};
+    assert_eq!(gene.set_value(0.7).get_id(), 1);
+    assert_eq!(gene.set_value(-3.0).get_id(), 0);
+    assert_eq!(gene.set_value(5.0).get_id(), 1);
+}
+
+#[test]
+fn test_onemax_and_leading_ones(){
+    let onemax = evaluate(OneMax::new(), &binary("1101101"));
+    assert_eq!(onemax.get_fitness(), 5.0);
+    assert_eq!(onemax.optimum(), 7.0);
+    assert!(!onemax.is_optimal());
+    assert!(evaluate(OneMax::new(), &binary("1111111")).is_optimal());
+
+    let leading_ones = evaluate(LeadingOnes::new(), &binary("1101101"));
+    assert_eq!(leading_ones.get_fitness(), 2.0);
+    assert!(evaluate(LeadingOnes::new(), &binary("1111111")).is_optimal());
+    assert!(OneMax::problem_solving() == ProblemSolving::Maximization);
+}
+
+#[test]
+fn test_trap(){
+    //Blocks of 4 bits, with a last block of 2 bits
+    assert_eq!(evaluate(Trap::<4>::new(), &binary("0000000000")).get_fitness(), 3.0 + 3.0 + 1.0);
+    assert_eq!(evaluate(Trap::<4>::new(), &binary("1110100001")).get_fitness(), 0.0 + 2.0 + 0.0);
+    let optimal = evaluate(Trap::<4>::new(), &binary("1111111111"));
+    assert_eq!(optimal.get_fitness(), 10.0);
+    assert!(optimal.is_optimal());
+}
+
+#[test]
+fn test_nk_landscape_optimum(){
+    for (n, k) in [(6, 0), (8, 2), (9, 3), (5, 4)] {
+        let instance = Arc::new(NkInstance::new(n, k, 42));
+
+        //The optimum of the dynamic programming is the best of all the solutions
+        let mut best = f64::NEG_INFINITY;
+        for bits in 0..1usize << n {
+            let dna: Vec<BinaryGene> = (0..n).map(|i| BinaryGene{id: ((bits >> i) & 1) as i32}).collect();
+            let individual = evaluate(NkLandscape::with_instance(instance.clone()), &dna);
+            best = best.max(individual.get_fitness());
+        }
+        assert!((instance.optimum() - best).abs() < 1e-12, "N={} K={}", n, k);
+        assert!(best > 0.0 && best < 1.0);
+    }
+
+    //The instances are reproducible from the seed
+    assert_eq!(NkInstance::new(10, 3, 1), NkInstance::new(10, 3, 1));
+    assert_ne!(NkInstance::new(10, 3, 1), NkInstance::new(10, 3, 2));
+}
+
+#[test]
+#[should_panic(expected = "The NK landscape individual has no instance.")]
+fn test_nk_landscape_without_instance(){
+    evaluate(NkLandscape::new(), &binary("0101"));
+}
+
+#[test]
+fn test_knapsack(){
+    //P01 instance of the knapsack problems of Kreher and Stinson, with optimum 309
+    let instance = Arc::new(KnapsackInstance::new(vec![23, 31, 29, 44, 53, 38, 63, 85, 89, 82], vec![92, 57, 49, 68, 60, 43, 67, 84, 87, 72], 165));
+    assert_eq!(instance.optimum(), 309.0);
+
+    let optimal = evaluate(Knapsack::with_instance(instance.clone()), &binary("1111010000"));
+    assert_eq!(optimal.get_fitness(), 309.0);
+    assert!(optimal.is_optimal());
+
+    //The infeasible selections are worse than any feasible one
+    let infeasible = evaluate(Knapsack::with_instance(instance.clone()), &binary("1111110000"));
+    assert_eq!(infeasible.get_fitness(), 165.0 - 218.0);
+}
+
+#[test]
+fn test_n_queens(){
+    let solution: Vec<IntegerGene> = [1, 3, 5, 7, 2, 0, 6, 4].iter().map(|id| IntegerGene{id: *id}).collect();
+    let queens = evaluate(NQueens::new(), &solution);
+    assert_eq!(queens.get_fitness(), 0.0);
+    assert!(queens.is_optimal());
+    assert!(NQueens::problem_solving() == ProblemSolving::Minimization);
+
+    //All the queens in the same diagonal
+    let diagonal = evaluate(NQueens::new(), &NQueens::alleles(5));
+    assert_eq!(diagonal.get_fitness(), 10.0);
+}
+
+#[test]
+fn test_continuous_functions_optima(){
+    let mut sphere = Continuous::<Sphere>::optimal(10);
+    sphere.calculate_fitness();
+    assert!(sphere.is_optimal());
+    let mut rastrigin = Continuous::<Rastrigin>::optimal(10);
+    rastrigin.calculate_fitness();
+    assert!(rastrigin.is_optimal());
+    let mut rosenbrock = Continuous::<Rosenbrock>::optimal(10);
+    rosenbrock.calculate_fitness();
+    assert!(rosenbrock.is_optimal());
+    let mut ackley = Continuous::<Ackley>::optimal(10);
+    ackley.calculate_fitness();
+    assert!(ackley.is_optimal());
+    let mut schwefel = Continuous::<Schwefel>::optimal(10);
+    schwefel.calculate_fitness();
+    assert!(schwefel.is_optimal());
+
+    //Away from the optimum
+    let mut rastrigin = Continuous::<Rastrigin>::optimal(2);
+    rastrigin.set_dna(&Continuous::<Rastrigin>::alleles(3)[..2]);
+    rastrigin.calculate_fitness();
+    assert!(rastrigin.get_fitness() > 1.0);
+}
+
+#[test]
+fn test_continuous_alleles_and_bounds(){
+    let alleles = Continuous::<Sphere>::alleles(5);
+    for (gene, value) in alleles.iter().zip([-5.12, -2.56, 0.0, 2.56, 5.12]) {
+        assert!((gene.value - value).abs() < 1e-12);
+    }
+
+    //The values are clamped to the domain
+    let mut sphere = Continuous::<Sphere>::optimal(2);
+    let mut gene = sphere.get_dna()[0];
+    gene.set_value(12.0);
+    sphere.set_gene(0, gene);
+    assert_eq!(sphere.get_dna()[0].get_value(), 5.12);
+}
+
+#[test]
+fn test_onemax_run(){
+    rng::set_seed(Some(3));
+    let mut ga = Ga::<OneMax>::new();
+    ga.with_problem_solving(OneMax::problem_solving())
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(10)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Creep)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(20)
+        .with_genes_per_individual(12)
+        .with_alleles_can_be_repeated(true)
+        .with_alleles(BinaryGene::alleles())
+        .with_max_generations(100);
+    let population = ga.run();
+    rng::set_seed(None);
+
+    //The bits are repeated within the dna, more than the two alleles
+    let best = population.individuals.iter().map(|individual| individual.get_fitness()).fold(f64::NEG_INFINITY, f64::max);
+    assert!(population.individuals.iter().all(|individual| individual.get_dna().len() == 12));
+    assert!(best >= 10.0);
+}
+
+#[test]
+fn test_knapsack_run_with_prototype(){
+    let instance = Arc::new(KnapsackInstance::new(vec![23, 31, 29, 44, 53, 38, 63, 85, 89, 82], vec![92, 57, 49, 68, 60, 43, 67, 84, 87, 72], 165));
+    let mut ga = Ga::<Knapsack>::new();
+    ga.with_problem_solving(Knapsack::problem_solving())
+        .with_selection_method(Selection::Tournament)
+        .with_number_of_couples(10)
+        .with_crossover_method(Crossover::Uniform)
+        .with_mutation_method(Mutation::Creep)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(20)
+        .with_genes_per_individual(10)
+        .with_alleles_can_be_repeated(true)
+        .with_alleles(BinaryGene::alleles())
+        .with_max_generations(20);
+    ga.with_prototype(Knapsack::with_instance(instance.clone()));
+    let population = ga.run();
+
+    //Every individual is evaluated with the instance of the prototype
+    assert!(population.individuals.iter().all(|individual| individual.instance.is_some()));
+    assert!(population.individuals.iter().all(|individual| individual.get_fitness() <= 309.0));
+}
@@ -0,0 +1,44 @@
+use genetic_algorithms::{schedule, configuration::Schedule};
+
+#[test]
+fn test_constant_schedule(){
+    assert_eq!(schedule::probability(Schedule::Constant, 10, 100, Some(0.8), Some(0.2)), Some(0.8));
+    assert_eq!(schedule::probability(Schedule::Constant, 10, 100, None, None), None);
+}
+
+#[test]
+fn test_linear_schedule(){
+    assert_eq!(schedule::probability(Schedule::Linear, 0, 11, Some(0.8), Some(0.3)), Some(0.8));
+    assert!((schedule::probability(Schedule::Linear, 5, 11, Some(0.8), Some(0.3)).unwrap() - 0.55).abs() < 1e-9);
+    assert!((schedule::probability(Schedule::Linear, 10, 11, Some(0.8), Some(0.3)).unwrap() - 0.3).abs() < 1e-9);
+}
+
+#[test]
+fn test_exponential_schedule(){
+    let probability = schedule::probability(Schedule::Exponential { rate: 0.1 }, 10, 100, Some(1.0), Some(0.0)).unwrap();
+    assert!((probability - (-1.0_f64).exp()).abs() < 1e-9);
+}
+
+#[test]
+fn test_cosine_schedule(){
+    assert_eq!(schedule::probability(Schedule::Cosine, 0, 11, Some(0.9), Some(0.1)), Some(0.9));
+    assert!((schedule::probability(Schedule::Cosine, 5, 11, Some(0.9), Some(0.1)).unwrap() - 0.5).abs() < 1e-9);
+    assert!((schedule::probability(Schedule::Cosine, 10, 11, Some(0.9), Some(0.1)).unwrap() - 0.1).abs() < 1e-9);
+}
+
+#[test]
+fn test_step_schedule(){
+    let step = Schedule::Step { step_size: 10, factor: 0.5 };
+    assert_eq!(schedule::probability(step, 9, 100, Some(0.8), Some(0.15)), Some(0.8));
+    assert_eq!(schedule::probability(step, 10, 100, Some(0.8), Some(0.15)), Some(0.4));
+    assert_eq!(schedule::probability(step, 25, 100, Some(0.8), Some(0.15)), Some(0.2));
+
+    //The probability never goes below the minimum
+    assert_eq!(schedule::probability(step, 30, 100, Some(0.8), Some(0.15)), Some(0.15));
+}
+
+#[test]
+fn test_exponential_schedule_without_rate(){
+    assert_eq!(genetic_algorithms::helpers::condition_checker::schedule_parameters(Schedule::Exponential { rate: 0.0 }, "mutation_configuration"),
+               Err("The rate of the exponential schedule in the mutation_configuration must be greater than 0.".to_string()));
+}
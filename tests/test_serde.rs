@@ -0,0 +1,165 @@
+#![cfg(feature = "serde")]
+mod structures;
+use genetic_algorithms::{configuration::{GaConfiguration, ConfigurationError, ProblemSolving, Schedule, LimitConfiguration}, operations::{Selection, Crossover, Mutation, Survivor, Niching, OperatorSelection}, population::Population, traits::ConfigurationT};
+use structures::{Gene, Genotype};
+
+#[test]
+fn test_serde_configuration(){
+    let mut configuration = GaConfiguration::new();
+    configuration.with_problem_solving(ProblemSolving::Minimization)
+        .with_selection_method(Selection::Tournament)
+        .with_crossover_method(Crossover::MultiPoint)
+        .with_crossover_schedule(Schedule::Exponential { rate: 0.5 })
+        .with_mutation_method(Mutation::Scramble)
+        .with_survivor_method(Survivor::RestrictedTournament)
+        .with_niching_method(Niching::Clearing)
+        .with_lambda(30)
+        .with_max_generations(150);
+
+    //The configuration is the same after a round trip
+    let json = serde_json::to_string(&configuration).unwrap();
+    let deserialized: GaConfiguration = serde_json::from_str(&json).unwrap();
+    assert!(deserialized.limit_configuration.problem_solving == ProblemSolving::Minimization);
+    assert_eq!(deserialized.limit_configuration.max_generations, 150);
+    assert_eq!(deserialized.selection_configuration.method, Selection::Tournament);
+    assert_eq!(deserialized.crossover_configuration.method, Crossover::MultiPoint);
+    assert_eq!(deserialized.crossover_configuration.schedule, Schedule::Exponential { rate: 0.5 });
+    assert_eq!(deserialized.mutation_configuration.method, Mutation::Scramble);
+    assert_eq!(deserialized.survivor, Survivor::RestrictedTournament);
+    assert_eq!(deserialized.niching_configuration.method, Some(Niching::Clearing));
+    assert_eq!(deserialized.lambda, Some(30));
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+}
+
+#[test]
+fn test_serde_enums(){
+    assert_eq!(serde_json::to_string(&Selection::RouletteWheel).unwrap(), "\"RouletteWheel\"");
+    assert_eq!(serde_json::from_str::<Mutation>("\"Gaussian\"").unwrap(), Mutation::Gaussian);
+    assert!(serde_json::from_str::<ProblemSolving>("\"FixedFitness\"").unwrap() == ProblemSolving::FixedFitness);
+    assert!(serde_json::from_str::<Crossover>("\"Unknown\"").is_err());
+
+    let limit_configuration: LimitConfiguration = serde_json::from_str(&serde_json::to_string(&LimitConfiguration{fitness_target: Some(2.5), ..Default::default()}).unwrap()).unwrap();
+    assert_eq!(limit_configuration.fitness_target, Some(2.5));
+}
+
+#[test]
+fn test_serde_population(){
+    let mut population = Population::new(vec![Genotype{dna: vec![Gene{id: 1}, Gene{id: 2}], fitness: 3.0, age: 1},
+                                               Genotype{dna: vec![Gene{id: 2}, Gene{id: 1}], fitness: 1.0, age: 2}]);
+    population.f_max = 3.0;
+    population.f_avg = 2.0;
+
+    let deserialized: Population<Genotype> = serde_json::from_str(&serde_json::to_string(&population).unwrap()).unwrap();
+    assert_eq!(deserialized.individuals, population.individuals);
+    assert_eq!(deserialized.f_max, 3.0);
+    assert_eq!(deserialized.f_avg, 2.0);
+}
+
+#[test]
+fn test_configuration_from_toml_str(){
+    let configuration = GaConfiguration::from_toml_str(r#"
+        adaptive_ga = true
+        survivor = "MuPlusLambda"
+        lambda = 40
+
+        [limit_configuration]
+        problem_solving = "Maximization"
+        max_generations = 250
+
+        [selection_configuration]
+        method = "RouletteWheel"
+
+        [crossover_configuration]
+        method = "MultiPoint"
+        number_of_points = 2
+        probability_max = 0.9
+        probability_min = 0.4
+        schedule = { Step = { step_size = 10, factor = 0.5 } }
+
+        [mutation_configuration]
+        method = "Inversion"
+    "#).unwrap();
+
+    assert!(configuration.adaptive_ga);
+    assert_eq!(configuration.survivor, Survivor::MuPlusLambda);
+    assert_eq!(configuration.lambda, Some(40));
+    assert!(configuration.limit_configuration.problem_solving == ProblemSolving::Maximization);
+    assert_eq!(configuration.limit_configuration.max_generations, 250);
+    assert_eq!(configuration.selection_configuration.method, Selection::RouletteWheel);
+    assert_eq!(configuration.crossover_configuration.method, Crossover::MultiPoint);
+    assert_eq!(configuration.crossover_configuration.number_of_points, Some(2));
+    assert_eq!(configuration.crossover_configuration.schedule, Schedule::Step { step_size: 10, factor: 0.5 });
+    assert_eq!(configuration.mutation_configuration.method, Mutation::Inversion);
+
+    //The keys that are not set take their default values
+    assert_eq!(configuration.selection_configuration.number_of_couples, GaConfiguration::default().selection_configuration.number_of_couples);
+    assert_eq!(configuration.niching_configuration.window_size, 20);
+}
+
+#[test]
+fn test_configuration_from_json_str(){
+    let configuration = GaConfiguration::from_json_str(r#"{"survivor": "Age", "mutation_configuration": {"method": "Gaussian", "step_size": 0.5}}"#).unwrap();
+    assert_eq!(configuration.survivor, Survivor::Age);
+    assert_eq!(configuration.mutation_configuration.method, Mutation::Gaussian);
+    assert_eq!(configuration.mutation_configuration.step_size, Some(0.5));
+}
+
+#[test]
+fn test_configuration_errors(){
+    //Unknown keys
+    let error = GaConfiguration::from_toml_str("[selection_configuration]\nmethods = \"Tournament\"").err().unwrap();
+    assert!(matches!(error, ConfigurationError::Parse(_)));
+    assert!(error.to_string().contains("unknown field `methods`"));
+
+    //Unknown operators
+    let error = GaConfiguration::from_json_str(r#"{"crossover_configuration": {"method": "Arithmetic"}}"#).err().unwrap();
+    assert!(matches!(error, ConfigurationError::Parse(_)));
+    assert!(error.to_string().contains("unknown variant `Arithmetic`"));
+
+    //Invalid values
+    let error = GaConfiguration::from_toml_str("[mutation_configuration]\nprobability_max = 1.5").err().unwrap();
+    assert!(matches!(error, ConfigurationError::Invalid(_)));
+    assert!(error.to_string().contains("The probability_max in the mutation_configuration must be between 0.0 and 1.0"));
+    assert!(matches!(GaConfiguration::from_toml_str("survivor = \"MuCommaLambda\"").err().unwrap(), ConfigurationError::Invalid(_)));
+
+    //Files that don't exist
+    assert!(matches!(GaConfiguration::from_toml_file("configuration_that_does_not_exist.toml").err().unwrap(), ConfigurationError::Io(_)));
+}
+
+#[test]
+fn test_configuration_to_toml(){
+    let mut configuration = GaConfiguration::new();
+    configuration.with_problem_solving(ProblemSolving::FixedFitness)
+        .with_fitness_target(0.0)
+        .with_crossover_method(Crossover::Cycle)
+        .with_mutation_schedule(Schedule::Exponential { rate: 0.1 })
+        .with_operator_selection_method(OperatorSelection::AdaptivePursuit)
+        .with_mutation_operators(vec![Mutation::Swap, Mutation::Scramble])
+        .with_history_path("history.csv".to_string());
+
+    let toml = configuration.to_toml().unwrap();
+    let deserialized = GaConfiguration::from_toml_str(&toml).unwrap();
+    assert!(deserialized.limit_configuration.problem_solving == ProblemSolving::FixedFitness);
+    assert_eq!(deserialized.limit_configuration.fitness_target, Some(0.0));
+    assert_eq!(deserialized.crossover_configuration.method, Crossover::Cycle);
+    assert_eq!(deserialized.mutation_configuration.schedule, Schedule::Exponential { rate: 0.1 });
+    assert_eq!(deserialized.operator_selection_configuration.method, Some(OperatorSelection::AdaptivePursuit));
+    assert_eq!(deserialized.operator_selection_configuration.mutation_operators, vec![Mutation::Swap, Mutation::Scramble]);
+    assert_eq!(deserialized.history_configuration.history_path, "history.csv");
+    assert_eq!(deserialized.to_toml().unwrap(), toml);
+}
+
+#[test]
+fn test_configuration_files(){
+    let directory = std::env::temp_dir();
+    let (toml_path, json_path) = (directory.join(format!("genetic_algorithms_{}_configuration.toml", std::process::id())),
+                                  directory.join(format!("genetic_algorithms_{}_configuration.json", std::process::id())));
+    std::fs::write(&toml_path, "[limit_configuration]\nmax_generations = 30").unwrap();
+    std::fs::write(&json_path, r#"{"limit_configuration": {"max_generations": 40}}"#).unwrap();
+
+    assert_eq!(GaConfiguration::from_toml_file(&toml_path).unwrap().limit_configuration.max_generations, 30);
+    assert_eq!(GaConfiguration::from_json_file(&json_path).unwrap().limit_configuration.max_generations, 40);
+
+    std::fs::remove_file(toml_path).unwrap();
+    std::fs::remove_file(json_path).unwrap();
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod structures;
+use genetic_algorithms::{statistics::{self, GenerationStatistics, Diversity}, cache::CacheStatistics};
+use crate::structures::{Gene, Genotype};
+
+#[test]
+fn test_generation_statistics(){
+    let individuals: Vec<Genotype> = [4.0, 1.0, 3.0, 2.0, f64::NAN].iter()
+        .map(|fitness| Genotype{dna: vec![Gene{id:1}], fitness: *fitness, age: 0}).collect();
+
+    //The individuals with a NaN fitness are not taken into account
+    let statistics = GenerationStatistics::new(3, &individuals, 0.5, 25, None);
+    assert_eq!(statistics.generation, 3);
+    assert_eq!(statistics.min, 1.0);
+    assert_eq!(statistics.max, 4.0);
+    assert_eq!(statistics.mean, 2.5);
+    assert_eq!(statistics.median, 2.5);
+    assert_eq!(statistics.std_dev, 1.25_f64.sqrt());
+    assert_eq!(statistics.best_so_far, 0.5);
+    assert_eq!(statistics.evaluations, 25);
+
+    //The median of an odd number of individuals is the middle one
+    let statistics = GenerationStatistics::new(0, &individuals[..3], 1.0, 3, None);
+    assert_eq!(statistics.median, 3.0);
+}
+
+#[test]
+fn test_generation_statistics_empty(){
+    let statistics = GenerationStatistics::new::<Genotype>(0, &[], 0.0, 0, None);
+    assert!(statistics.min.is_nan());
+    assert!(statistics.mean.is_nan());
+    assert!(statistics.std_dev.is_nan());
+}
+
+#[test]
+fn test_statistics_to_csv(){
+    let individuals = vec![Genotype{dna: vec![Gene{id:1}], fitness: 1.0, age: 0}, Genotype{dna: vec![Gene{id:1}], fitness: 3.0, age: 0}];
+    let diversity = Diversity{mean_distance: 0.5, entropy: 0.25, unique_genotypes: 2};
+    let cache = CacheStatistics{hits: 3, misses: 1, hit_rate: 0.75};
+    let history = vec![GenerationStatistics::new(0, &individuals, 1.0, 2, None), GenerationStatistics{skipped_evaluations: 6, cache: Some(cache), ..GenerationStatistics::new(1, &individuals, 1.0, 4, Some(diversity))}];
+
+    let csv = statistics::to_csv(&history);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "generation,min,max,mean,median,std_dev,best_so_far,evaluations,skipped_evaluations,elapsed_seconds,crossover_probability,mutation_probability,mean_distance,entropy,unique_genotypes,cache_hits,cache_misses,cache_hit_rate");
+    assert_eq!(lines[1], "0,1,3,2,2,1,1,2,0,0,1,1,,,,,,");
+    assert_eq!(lines[2], "1,1,3,2,2,1,1,4,6,0,1,1,0.5,0.25,2,3,1,0.75");
+}
+
+#[test]
+fn test_statistics_to_json_lines(){
+    let individuals = vec![Genotype{dna: vec![Gene{id:1}], fitness: 1.0, age: 0}, Genotype{dna: vec![Gene{id:1}], fitness: 3.0, age: 0}];
+    let diversity = Diversity{mean_distance: 0.5, entropy: 0.25, unique_genotypes: 2};
+    let cache = CacheStatistics{hits: 3, misses: 1, hit_rate: 0.75};
+    let history = vec![GenerationStatistics{elapsed_seconds: 1.5, mutation_probability: 0.2, ..GenerationStatistics::new(0, &individuals, f64::INFINITY, 2, None)},
+                       GenerationStatistics{skipped_evaluations: 6, cache: Some(cache), ..GenerationStatistics::new(1, &individuals, 1.0, 4, Some(diversity))}];
+
+    //The values that are not numbers are null
+    let json_lines = statistics::to_json_lines(&history);
+    let lines: Vec<&str> = json_lines.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "{\"generation\":0,\"min\":1,\"max\":3,\"mean\":2,\"median\":2,\"std_dev\":1,\"best_so_far\":null,\"evaluations\":2,\"skipped_evaluations\":0,\"elapsed_seconds\":1.5,\"crossover_probability\":1,\"mutation_probability\":0.2,\"diversity\":null,\"cache\":null}");
+    assert_eq!(lines[1], "{\"generation\":1,\"min\":1,\"max\":3,\"mean\":2,\"median\":2,\"std_dev\":1,\"best_so_far\":1,\"evaluations\":4,\"skipped_evaluations\":6,\"elapsed_seconds\":0,\"crossover_probability\":1,\"mutation_probability\":1,\"diversity\":{\"mean_distance\":0.5,\"entropy\":0.25,\"unique_genotypes\":2},\"cache\":{\"hits\":3,\"misses\":1,\"hit_rate\":0.75}}");
+}
@@ -0,0 +1,196 @@
+use std::{fs, sync::Arc};
+use genetic_algorithms::{operations::{Crossover, Mutation, Selection, Survivor}, rng, traits::{ConfigurationT, GenotypeT},
+    problems::{Problem, IntegerGene, tsp::{EdgeWeightType, Tour, TspInstance, TsplibError, tour_from_tsplib_file, tour_from_tsplib_str}}};
+
+const BURMA14: &str = "NAME: burma14
+TYPE: TSP
+COMMENT: 14-Staedte in Burma (Zaw Win)
+DIMENSION: 14
+EDGE_WEIGHT_TYPE: GEO
+EDGE_WEIGHT_FORMAT: FUNCTION
+DISPLAY_DATA_TYPE: COORD_DISPLAY
+NODE_COORD_SECTION
+   1  16.47       96.10
+   2  16.47       94.44
+   3  20.09       92.54
+   4  22.39       93.37
+   5  25.23       97.24
+   6  22.00       96.05
+   7  20.47       97.02
+   8  17.20       96.29
+   9  16.30       97.38
+  10  14.05       98.12
+  11  16.53       97.38
+  12  21.52       95.59
+  13  19.41       97.13
+  14  20.09       94.55
+EOF
+";
+
+const BURMA14_TOUR: &str = "NAME : burma14.opt.tour
+COMMENT : Optimal tour for burma14 (3323)
+TYPE : TOUR
+DIMENSION : 14
+TOUR_SECTION
+1
+2
+14
+3
+4
+5
+6
+12
+7
+13
+8
+11
+9
+10
+-1
+EOF
+";
+
+fn euclidean_square() -> String {
+    String::from("NAME : square\nTYPE : TSP\nDIMENSION : 4\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n1 0 0\n2 3 4\n3 3 0\n4 0 4\nEOF\n")
+}
+
+fn explicit(format: &str, weights: &str) -> TspInstance {
+    TspInstance::from_tsplib_str(&format!("NAME: explicit\nTYPE: TSP\nDIMENSION: 4\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_FORMAT: {}\nEDGE_WEIGHT_SECTION\n{}\nEOF\n", format, weights)).unwrap()
+}
+
+fn parse_error(content: &str) -> String {
+    match TspInstance::from_tsplib_str(content) {
+        Err(TsplibError::Parse(message)) => message,
+        _ => panic!("The content should not be parsed"),
+    }
+}
+
+#[test]
+fn test_euclidean_instance(){
+    let instance = TspInstance::from_tsplib_str(&euclidean_square()).unwrap();
+    assert_eq!(instance.name, "square");
+    assert_eq!(instance.dimension, 4);
+    assert_eq!(instance.edge_weight_type, EdgeWeightType::Euc2d);
+    assert_eq!(instance.coordinates[1], (3.0, 4.0));
+    assert_eq!(instance.distance(0, 1), 5.0);
+    assert_eq!(instance.distance(0, 2), 3.0);
+    assert_eq!(instance.distance(2, 2), 0.0);
+    assert_eq!(instance.tour_length(&[0, 2, 1, 3]), 14.0);
+    assert_eq!(instance.tour_length(&[0, 1, 2, 3]), 18.0);
+}
+
+#[test]
+fn test_pseudo_euclidean_distance(){
+    let instance = TspInstance::from_tsplib_str("NAME: att\nDIMENSION: 3\nEDGE_WEIGHT_TYPE: ATT\nNODE_COORD_SECTION\n1 0 0\n2 10 0\n3 0 30\n").unwrap();
+
+    //sqrt(100 / 10) = 3.16 is rounded up, and sqrt(900 / 10) = 9.49 is rounded to 9 and then up to 10
+    assert_eq!(instance.distance(0, 1), 4.0);
+    assert_eq!(instance.distance(0, 2), 10.0);
+}
+
+#[test]
+fn test_geographical_instance_and_optimal_tour(){
+    let mut instance = TspInstance::from_tsplib_str(BURMA14).unwrap();
+    assert_eq!(instance.edge_weight_type, EdgeWeightType::Geo);
+    assert_eq!(instance.distance(0, 1), 153.0);
+
+    let tour = tour_from_tsplib_str(BURMA14_TOUR).unwrap();
+    assert_eq!(tour[..3], [0, 1, 13]);
+    assert_eq!(instance.tour_length(&tour), 3323.0);
+
+    //The optimum of the tours is the length of the optimal tour
+    instance.with_optimal_tour(tour.clone());
+    let mut optimal = Tour::with_instance(Arc::new(instance));
+    optimal.set_dna(&tour.iter().map(|city| IntegerGene{id: *city as i32}).collect::<Vec<IntegerGene>>());
+    optimal.calculate_fitness();
+    assert_eq!(optimal.get_fitness(), 3323.0);
+    assert!(optimal.is_optimal());
+}
+
+#[test]
+fn test_explicit_formats(){
+    let full = explicit("FULL_MATRIX", "0 1 2 3\n1 0 4 5\n2 4 0 6\n3 5 6 0");
+    for (format, weights) in [("UPPER_ROW", "1 2 3\n4 5\n6"), ("LOWER_ROW", "1\n2 4\n3 5 6"), ("UPPER_DIAG_ROW", "0 1 2 3 0 4 5 0 6 0"),
+                              ("LOWER_DIAG_ROW", "0 1 0 2 4 0 3 5 6 0"), ("UPPER_COL", "1 2 4 3 5 6"), ("LOWER_DIAG_COL", "0 1 2 3 0 4 5 0 6 0")] {
+        assert_eq!(explicit(format, weights).distances, full.distances, "{}", format);
+    }
+    assert_eq!(full.edge_weight_type, EdgeWeightType::Explicit);
+    assert!(full.coordinates.is_empty());
+    assert_eq!(full.tour_length(&[0, 1, 2, 3]), 1.0 + 4.0 + 6.0 + 3.0);
+}
+
+#[test]
+fn test_parse_errors(){
+    assert_eq!(parse_error("NAME: a\nEDGE_WEIGHT_TYPE: EUC_2D\n"), "The DIMENSION is missing.");
+    assert_eq!(parse_error("DIMENSION: 2\nEDGE_WEIGHT_TYPE: EUC_3D\n"), "The edge weight type EUC_3D is not supported.");
+    assert_eq!(parse_error("DIMENSION: 3\nEDGE_WEIGHT_TYPE: EUC_2D\nNODE_COORD_SECTION\n1 0 0\n2 1 1\n"), "The NODE_COORD_SECTION must have 3 nodes with 2 coordinates.");
+    assert_eq!(parse_error("DIMENSION: 2\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_FORMAT: UPPER_ROW\nEDGE_WEIGHT_SECTION\n1 2\n"),
+               "The EDGE_WEIGHT_SECTION must have 1 weights for the UPPER_ROW format, and it has 2.");
+    assert_eq!(parse_error("DIMENSION: 2\nEDGE_WEIGHT_TYPE: EUC_2D\n1 0 0\n"), "The line 3 is data outside of a section.");
+    assert!(matches!(tour_from_tsplib_str("DIMENSION: 3\nTOUR_SECTION\n1 2 2 -1\n"), Err(TsplibError::Parse(_))));
+    assert!(matches!(TspInstance::from_tsplib_file("/nonexistent/instance.tsp"), Err(TsplibError::Io(_))));
+}
+
+#[test]
+fn test_files(){
+    let instance_path = std::env::temp_dir().join(format!("genetic_algorithms_{}_burma14.tsp", std::process::id()));
+    let tour_path = std::env::temp_dir().join(format!("genetic_algorithms_{}_burma14.opt.tour", std::process::id()));
+    fs::write(&instance_path, BURMA14).unwrap();
+    fs::write(&tour_path, BURMA14_TOUR).unwrap();
+
+    let instance = TspInstance::from_tsplib_file(&instance_path).unwrap();
+    assert_eq!(instance.tour_length(&tour_from_tsplib_file(&tour_path).unwrap()), 3323.0);
+
+    fs::remove_file(instance_path).unwrap();
+    fs::remove_file(tour_path).unwrap();
+}
+
+#[test]
+fn test_tour_run(){
+    rng::set_seed(Some(5));
+    let instance = Arc::new(TspInstance::from_tsplib_str(BURMA14).unwrap());
+    let mut ga = Tour::ga(instance.clone());
+    ga.with_selection_method(Selection::Tournament)
+        .with_number_of_couples(10)
+        .with_crossover_method(Crossover::Cycle)
+        .with_mutation_method(Mutation::Inversion)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(20)
+        .with_max_generations(30);
+    let population = ga.run();
+    rng::set_seed(None);
+
+    //The tours are permutations of the cities, and their fitness is their length
+    for tour in &population.individuals {
+        let mut cities = tour.cities();
+        assert_eq!(tour.get_fitness(), instance.tour_length(&cities));
+        cities.sort();
+        assert_eq!(cities, (0..14).collect::<Vec<usize>>());
+        assert!(tour.get_fitness() >= 3323.0);
+    }
+}
+
+#[test]
+fn test_nearest_neighbour_seeding(){
+    let square = TspInstance::from_tsplib_str(&euclidean_square()).unwrap();
+    assert_eq!(square.nearest_neighbour_tour(0), vec![0, 2, 1, 3]);
+    assert_eq!(square.nearest_neighbour_tour(1), vec![1, 3, 0, 2]);
+
+    //The run starts from the nearest neighbour tours, so it is never worse than them
+    let instance = Arc::new(TspInstance::from_tsplib_str(BURMA14).unwrap());
+    let nearest = (0..14).map(|start| instance.tour_length(&instance.nearest_neighbour_tour(start))).fold(f64::MAX, f64::min);
+    let mut ga = Tour::ga(instance.clone());
+    ga.with_selection_method(Selection::Tournament)
+        .with_number_of_couples(10)
+        .with_crossover_method(Crossover::Cycle)
+        .with_mutation_method(Mutation::Inversion)
+        .with_survivor_method(Survivor::Fitness)
+        .with_population_size(20)
+        .with_max_generations(10);
+    ga.with_initializer(Tour::nearest_neighbour(instance.clone(), 14));
+    let population = ga.run();
+
+    let best = population.individuals.iter().map(|tour| tour.get_fitness()).fold(f64::MAX, f64::min);
+    assert!(best <= nearest);
+    assert!(best >= 3323.0);
+}